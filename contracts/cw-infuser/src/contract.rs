@@ -1,29 +1,55 @@
 use crate::error::{AnyOfErr, ContractError};
-use crate::msg::{ExecuteMsg, InfusionsResponse, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::msg::{
+    CollectionInfusions, ExecuteMsg, InfusionStatsResponse, InfusionSummary, InfusionsResponse,
+    InstantiateMsg, LeaderboardEntry, MigrateMsg, ProvenanceEntry, QueryMsg, QueryOrder,
+    ReceiveCw20Msg, ReceiveNftMsg, ReferralStatsResponse, SimulateInfuseResponse,
+};
 use crate::state::{
-    Config, TokenPositionMapping, UpdatingConfig, CONFIG, ELIGIBLE_COLLECTION, INFUSION,
-    INFUSION_ID, MINTABLE_NUM_TOKENS, MINTABLE_TOKEN_VECTORS, MINT_COUNT, WAVS_ADMIN, WAVS_TRACKED,
+    burner_stats, infusions, provenance, BurnerStats, CollectionBurnCount, Config, EscrowedNft,
+    Provenance, TokenPositionMapping, UpdatingConfig, CONFIG, ELIGIBLE_COLLECTION, ESCROWED_FEES,
+    ESCROWED_NFTS, FEE_BALANCES, INFUSED_INGREDIENTS, INFUSION_ID, INFUSION_STATS,
+    MINTABLE_NUM_TOKENS, MINTABLE_TOKEN_VECTORS, MINT_COUNT, PENDING_BUNDLES, REFERRAL_STATS,
+    ROYALTY_RECIPIENTS, WAVS_CREDITED_BURNS, WAVS_NONCES, WAVS_OPERATORS, WAVS_THRESHOLD,
+    WAVS_TRACKED,
 };
 use cosmwasm_schema::serde::Serialize;
 use cosmwasm_std::{
-    coin, entry_point, instantiate2_address, to_json_binary, Addr, Attribute, BankMsg, Binary,
-    Coin, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env, Event, Fraction, HexBinary, MessageInfo,
-    QuerierWrapper, QueryRequest, Response, StdError, StdResult, Storage, Uint128, WasmMsg,
-    WasmQuery,
+    coin, entry_point, from_json, instantiate2_address, to_json_binary, Addr, Api, Attribute,
+    BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env, Event, Fraction,
+    HexBinary, MessageInfo, Order, QuerierWrapper, QueryRequest, Response, StdError, StdResult,
+    Storage, Timestamp, Uint128, WasmMsg, WasmQuery,
 };
 use cw2::set_contract_version;
+use cw_storage_plus::Bound;
 
-use cw721::{Cw721QueryMsg, OwnerOfResponse};
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use cw721::{Cw721QueryMsg, Cw721ReceiveMsg, OwnerOfResponse};
 // use cw721_v18::Cw721ExecuteMsg;
 use cw_controllers::AdminError;
 
 use cw721_base::msg::{ExecuteMsg as Cw721ExecuteMessage, InstantiateMsg as Cw721InstantiateMsg};
 
 use cw_infusions::{
+    assets::{Asset, CW20_DENOM_PREFIX},
     bundles::{AnyOfCount, Bundle, BundleBlend, BundleType},
-    nfts::{CollectionInfo, InfusedCollection, RoyaltyInfoResponse, SgInstantiateMsg, NFT},
-    state::{EligibleNFTCollection, Infusion, InfusionState},
-    wavs::{WavsBundle, WavsMintCountResponse, WavsRecordResponse},
+    events::{
+        BurnEvent, ConfigChangeEvent, CreateEvent, FeeClaimEvent, FeeSplitEvent, InfuseEvent,
+        InfusionUpdateEvent, MintEvent, PendingBundleEvent, RoyaltyRecipientEvent,
+        RoyaltySkippedEvent, UninfuseEvent, WavsCreditEvent,
+    },
+    nfts::{
+        CollectionInfo, Cw2981Query, Cw2981QueryMsg, Cw2981RoyaltiesInfoResponse,
+        InfusedCollection, RoyaltyInfoResponse, SgInstantiateMsg, NFT,
+    },
+    pricing::{Decay, PricingStrategy},
+    state::{
+        EligibleNFTCollection, FeeRecipient, FungibleIngredient, Infusion, InfusionParamState,
+        InfusionState, IngredientDisposition,
+    },
+    wavs::{
+        wavs_signing_hash, WavsBundle, WavsKeyType, WavsMintCountResponse, WavsOperator,
+        WavsOperatorsResponse, WavsRecordResponse, WavsSignature,
+    },
 };
 
 use cw_infusions::CompatibleTraits;
@@ -41,6 +67,10 @@ use url::Url;
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:sg-minter";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+const DEFAULT_QUERY_LIMIT: u32 = 10;
+const MAX_QUERY_LIMIT: u32 = 30;
+/// Highest `min_held` of a fee discount, verified with a single cw721 `Tokens` query.
+const MAX_DISCOUNT_MIN_HELD: u32 = 100;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -71,20 +101,33 @@ pub fn instantiate(
     if msg
         .min_creation_fee
         .clone()
-        .is_some_and(|f| f.amount.u128() == 0u128)
+        .is_some_and(|f| f.amount().u128() == 0u128)
     {
         return Err(ContractError::Std(StdError::generic_err(
             "admin fee incorrect. Must be less than 100%",
         )));
     }
+    let accepted_creation_fees = msg.accepted_creation_fees.unwrap_or_default();
+    if accepted_creation_fees.iter().any(|f| f.amount().is_zero()) {
+        return Err(ContractError::InfusionFeeCannotbeZero);
+    }
+    for fee in msg
+        .min_creation_fee
+        .iter()
+        .chain(&accepted_creation_fees)
+        .chain(&msg.min_infusion_fee)
+    {
+        fee.validate(deps.api)?;
+    }
 
     // admin is either sender or manually set.
     let mut contract_owner = info.sender;
     if let Some(ad) = msg.contract_owner {
         contract_owner = deps.api.addr_validate(&ad)?;
     }
-    if let Some(wavs) = msg.wavs_public_key {
-        WAVS_ADMIN.save(deps.storage, &wavs)?;
+    if let Some(op) = msg.wavs_public_key {
+        validate_wavs_operator(&op)?;
+        WAVS_OPERATORS.save(deps.storage, op.public_key.as_slice(), &op.key_type)?;
     }
 
     // get checksum of cw721
@@ -102,6 +145,8 @@ pub fn instantiate(
             max_bundles: msg.max_bundles.unwrap_or(5),
             owner_fee: msg.owner_fee,
             min_creation_fee: msg.min_creation_fee,
+            accepted_creation_fees,
+            escrow_fees: msg.escrow_fees.unwrap_or_default(),
             min_infusion_fee: msg.min_infusion_fee,
             // shuffle_fee: todo!(),
         },
@@ -120,7 +165,22 @@ pub fn execute(
         ExecuteMsg::CreateInfusion { infusions } => {
             execute_create_infusion(deps, info.clone(), env, infusions)
         }
-        ExecuteMsg::Infuse { id, bundle } => execute_infuse_bundle(deps, env, info, id, bundle),
+        ExecuteMsg::Infuse {
+            id,
+            bundle,
+            recipient,
+            referrer,
+        } => execute_infuse_bundle(deps, env, info, id, bundle, recipient, referrer),
+        ExecuteMsg::InfuseMany { items } => execute_infuse_many(deps, env, info, items),
+        ExecuteMsg::ReceiveNft(msg) => execute_receive_nft(deps, info, msg),
+        ExecuteMsg::Receive(msg) => execute_receive_cw20(deps, env, info, msg),
+        ExecuteMsg::CompleteBundle {
+            id,
+            recipient,
+            referrer,
+        } => execute_complete_bundle(deps, env, info, id, recipient, referrer),
+        ExecuteMsg::WithdrawPendingBundle { id } => execute_withdraw_pending_bundle(deps, info, id),
+        ExecuteMsg::Uninfuse { id, token_id } => execute_uninfuse(deps, info, id, token_id),
         ExecuteMsg::UpdateConfig { config } => update_config(deps, info, config),
         ExecuteMsg::EndInfusion { id } => execute_end_infusion(deps, info, id),
         ExecuteMsg::UpdateInfusionBaseUri { id, base_uri } => {
@@ -135,25 +195,180 @@ pub fn execute(
         ExecuteMsg::UpdateInfusionMintFee { id, mint_fee } => {
             update_infusion_mint_fee(deps, info, id, mint_fee)
         }
+        ExecuteMsg::ClaimFees { denoms } => execute_claim_fees(deps, info, denoms),
+        ExecuteMsg::RegisterRoyaltyRecipient {
+            collection,
+            recipient,
+        } => execute_register_royalty_recipient(deps, info, collection, recipient),
 
         ExecuteMsg::Shuffle { id } => execute_shuffle(deps, env, info, id),
-        ExecuteMsg::WavsEntryPoint { infusions } => {
-            update_wavs_infusion_state(deps, info, infusions)
-        }
+        ExecuteMsg::WavsSignedEntryPoint {
+            infusions,
+            nonce,
+            signatures,
+        } => execute_wavs_signed_entry_point(deps, env, infusions, nonce, signatures),
+        ExecuteMsg::UpdateWavsOperators {
+            to_add,
+            to_remove,
+            threshold,
+        } => update_wavs_operators(deps, info, to_add, to_remove, threshold),
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Infusion { addr, id } => to_json_binary(&query_infusion(deps, addr, id)?),
         QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
         QueryMsg::InfusionById { id } => to_json_binary(&query_infusion_by_id(deps, id)?),
         QueryMsg::Infusions { addr, index } => to_json_binary(&query_infusions(deps, addr, index)?),
+        QueryMsg::InfusionsByOwner {
+            owner,
+            start_after,
+            limit,
+            order,
+        } => to_json_binary(&query_infusions_by_owner(
+            deps,
+            owner,
+            start_after,
+            limit,
+            order,
+        )?),
+        QueryMsg::InfusionsByCollection {
+            addr,
+            start_after,
+            limit,
+            order,
+        } => to_json_binary(&query_infusions_by_collection(
+            deps,
+            addr,
+            start_after,
+            limit,
+            order,
+        )?),
+        QueryMsg::InfusionsForCollection {
+            addr,
+            start_after,
+            limit,
+        } => to_json_binary(&query_infusions_for_collection(
+            deps,
+            &addr,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::InfusionsForCollections { addrs, limit } => {
+            to_json_binary(&query_infusions_for_collections(deps, addrs, limit)?)
+        }
+        QueryMsg::AllInfusions {
+            enabled,
+            start_after,
+            limit,
+            order,
+        } => to_json_binary(&query_all_infusions(
+            deps,
+            enabled,
+            start_after,
+            limit,
+            order,
+        )?),
+        QueryMsg::WavsOperators {} => to_json_binary(&WavsOperatorsResponse {
+            operators: WAVS_OPERATORS
+                .range(deps.storage, None, None, Order::Ascending)
+                .map(|item| {
+                    item.map(|(public_key, key_type)| WavsOperator {
+                        key_type,
+                        public_key: Binary(public_key),
+                    })
+                })
+                .collect::<StdResult<Vec<_>>>()?,
+            threshold: WAVS_THRESHOLD.may_load(deps.storage)?.unwrap_or(1),
+        }),
+        QueryMsg::WavsOperatorNonce { public_key } => to_json_binary(
+            &WAVS_NONCES
+                .may_load(deps.storage, public_key.as_slice())?
+                .unwrap_or_default(),
+        ),
+        QueryMsg::WavsBurnCredited {
+            collection,
+            token_id,
+        } => to_json_binary(
+            &WAVS_CREDITED_BURNS.has(deps.storage, (collection.as_str(), token_id.as_str())),
+        ),
         QueryMsg::WavsRecord { burner, nfts } => {
             to_json_binary(&query_retrieve_wavs_record(deps, burner, nfts)?)
         }
         QueryMsg::InfusionGenetics { id } => to_json_binary(&query_infusion_genetics(deps, id)?),
+        QueryMsg::EscrowedNft { addr, token_id } => {
+            to_json_binary(&ESCROWED_NFTS.may_load(deps.storage, (&addr, token_id))?)
+        }
+        QueryMsg::InfusedIngredients { id, token_id } => to_json_binary(
+            &INFUSED_INGREDIENTS
+                .may_load(deps.storage, (id, token_id))?
+                .unwrap_or_default(),
+        ),
+        QueryMsg::InfusionStats { id } => to_json_binary(&query_infusion_stats(deps, id)?),
+        QueryMsg::Leaderboard { id, limit } => to_json_binary(&query_leaderboard(deps, id, limit)?),
+        QueryMsg::BurnerStats { id, addr } => to_json_binary(
+            &burner_stats()
+                .may_load(deps.storage, (id, addr))?
+                .unwrap_or(BurnerStats {
+                    infusion_id: id,
+                    burns: 0,
+                    mints: 0,
+                }),
+        ),
+        QueryMsg::Provenance { id, token_id } => {
+            to_json_binary(&provenance().may_load(deps.storage, (id, token_id))?)
+        }
+        QueryMsg::SimulateInfuse {
+            id,
+            sender,
+            referrer,
+            bundle,
+            funds,
+        } => to_json_binary(&query_simulate_infuse(
+            deps,
+            env.block.time,
+            id,
+            sender,
+            referrer,
+            bundle,
+            funds,
+        )?),
+        QueryMsg::CurrentPrice { id } => {
+            let infusion = query_infusion_by_id(deps, id)?;
+            to_json_binary(&current_mint_fee(deps, env.block.time, &infusion)?.map(Asset::from))
+        }
+        QueryMsg::BurnerHistory {
+            addr,
+            start_after,
+            limit,
+        } => to_json_binary(&query_burner_history(deps, addr, start_after, limit)?),
+        QueryMsg::FeeBalance { addr } => to_json_binary(
+            &FEE_BALANCES
+                .prefix(&addr)
+                .range(deps.storage, None, None, Order::Ascending)
+                .map(|item| item.map(|(denom, amount)| Asset::from(coin(amount.u128(), denom))))
+                .collect::<StdResult<Vec<_>>>()?,
+        ),
+        QueryMsg::EscrowedFees {} => to_json_binary(
+            &ESCROWED_FEES
+                .range(deps.storage, None, None, Order::Ascending)
+                .map(|item| item.map(|(denom, amount)| Asset::from(coin(amount.u128(), denom))))
+                .collect::<StdResult<Vec<_>>>()?,
+        ),
+        QueryMsg::RoyaltyRecipient { collection } => {
+            to_json_binary(&ROYALTY_RECIPIENTS.may_load(deps.storage, &collection)?)
+        }
+        QueryMsg::ReferralStats { addr } => {
+            let stats = REFERRAL_STATS
+                .may_load(deps.storage, &addr)?
+                .unwrap_or_default();
+            to_json_binary(&ReferralStatsResponse {
+                referrals: stats.referrals,
+                earned: stats.earned.into_iter().map(Asset::from).collect(),
+            })
+        }
     }
 }
 
@@ -176,7 +391,7 @@ fn update_infusion_eligible_collections(
 ) -> Result<Response, ContractError> {
     let cfg = CONFIG.load(deps.storage)?;
     let key = INFUSION_ID.load(deps.storage, id)?;
-    let mut infusion = INFUSION.load(deps.storage, key.clone())?;
+    let mut infusion = infusions().load(deps.storage, key.clone())?;
 
     if infusion.owner != msg.sender {
         return Err(ContractError::Admin(AdminError::NotAdmin {}));
@@ -187,12 +402,18 @@ fn update_infusion_eligible_collections(
         for i in 0..infusion.collections.len() {
             if infusion.collections[i].addr == nft {
                 infusion.collections.remove(i);
+                prune_eligible_collection(deps.storage, &nft, id)?;
                 break;
             }
         }
     }
     // ensure new eligible collection params
     if to_add.len() > 0 {
+        for col in &to_add {
+            if let Some(ps) = &col.payment_substitute {
+                ps.validate(deps.api)?;
+            }
+        }
         let collections = validate_eligible_collection_list(
             deps.storage,
             // deps.querier,
@@ -205,6 +426,7 @@ fn update_infusion_eligible_collections(
         )?;
         infusion.collections = collections;
     }
+    validate_fee_shares(&infusion.collections, &infusion.infusion_params)?;
 
     match &bt {
         BundleType::AllOf {} => {}
@@ -225,8 +447,25 @@ fn update_infusion_eligible_collections(
     }
     infusion.infusion_params.bundle_type = bt.clone();
 
-    INFUSION.save(deps.storage, key, &infusion)?;
-    Ok(Response::new())
+    infusions().save(deps.storage, key, &infusion)?;
+    Ok(Response::new()
+        .add_event(InfusionUpdateEvent {
+            infusion_id: id,
+            sender: &msg.sender,
+            field: "collections",
+            value: infusion
+                .collections
+                .iter()
+                .map(|c| c.addr.as_str())
+                .collect::<Vec<_>>()
+                .join(","),
+        })
+        .add_event(InfusionUpdateEvent {
+            infusion_id: id,
+            sender: &msg.sender,
+            field: "bundle_type",
+            value: bt.strain().to_string(),
+        }))
 }
 
 /// Update the baseuri used for infused collection metadata
@@ -237,13 +476,18 @@ fn update_infused_base_uri(
     base_uri: String,
 ) -> Result<Response, ContractError> {
     let key = INFUSION_ID.load(deps.storage, id)?;
-    let mut infusion = INFUSION.load(deps.storage, key.clone())?;
+    let mut infusion = infusions().load(deps.storage, key.clone())?;
     if infusion.owner != msg.sender {
         return Err(ContractError::Admin(AdminError::NotAdmin {}));
     }
-    infusion.infused_collection.base_uri = base_uri;
-    INFUSION.save(deps.storage, key, &infusion)?;
-    Ok(Response::new())
+    infusion.infused_collection.base_uri = base_uri.clone();
+    infusions().save(deps.storage, key, &infusion)?;
+    Ok(Response::new().add_event(InfusionUpdateEvent {
+        infusion_id: id,
+        sender: &msg.sender,
+        field: "base_uri",
+        value: base_uri,
+    }))
 }
 
 /// Update the mint fee for an infusion
@@ -251,17 +495,43 @@ fn update_infusion_mint_fee(
     deps: DepsMut,
     msg: MessageInfo,
     id: u64,
-    mint_fee: Option<Coin>,
+    mint_fee: Option<Asset>,
 ) -> Result<Response, ContractError> {
     let key = INFUSION_ID.load(deps.storage, id)?;
-    let mut infusion = INFUSION.load(deps.storage, key.clone())?;
+    let mut infusion = infusions().load(deps.storage, key.clone())?;
     if infusion.owner != msg.sender {
         return Err(ContractError::Admin(AdminError::NotAdmin {}));
     }
-    infusion.infusion_params.mint_fee = mint_fee;
-    INFUSION.save(deps.storage, key, &infusion)?;
+    if let Some(fee) = &mint_fee {
+        fee.validate(deps.api)?;
+    }
+    infusion.infusion_params.mint_fee = mint_fee.clone();
+    infusions().save(deps.storage, key, &infusion)?;
+
+    Ok(Response::new().add_event(InfusionUpdateEvent {
+        infusion_id: id,
+        sender: &msg.sender,
+        field: "mint_fee",
+        value: mint_fee.map(|f| f.to_string()).unwrap_or_default(),
+    }))
+}
 
-    Ok(Response::new())
+/// Removes an infusion id from the infusions a collection is eligible for.
+fn prune_eligible_collection(
+    storage: &mut dyn Storage,
+    nft: &Addr,
+    infusion_id: u64,
+) -> StdResult<()> {
+    let key = nft.to_string();
+    if let Some(mut ids) = ELIGIBLE_COLLECTION.may_load(storage, &key)? {
+        ids.retain(|i| *i != infusion_id);
+        if ids.is_empty() {
+            ELIGIBLE_COLLECTION.remove(storage, &key);
+        } else {
+            ELIGIBLE_COLLECTION.save(storage, &key, &ids)?;
+        }
+    }
+    Ok(())
 }
 
 pub fn execute_end_infusion(
@@ -270,7 +540,7 @@ pub fn execute_end_infusion(
     id: u64,
 ) -> Result<Response, ContractError> {
     let key = INFUSION_ID.load(deps.storage, id)?;
-    let mut infusion = INFUSION.load(deps.storage, key.clone())?;
+    let mut infusion = infusions().load(deps.storage, key.clone())?;
     if infusion.owner != info.sender {
         return Err(ContractError::Unauthorized {});
     }
@@ -278,10 +548,15 @@ pub fn execute_end_infusion(
         return Err(ContractError::InfusionIsEnded {});
     } else {
         infusion.enabled = false;
-        INFUSION.save(deps.storage, key, &infusion)?;
+        infusions().save(deps.storage, key, &infusion)?;
     }
 
-    Ok(Response::new())
+    Ok(Response::new().add_event(InfusionUpdateEvent {
+        infusion_id: id,
+        sender: &info.sender,
+        field: "enabled",
+        value: false.to_string(),
+    }))
 }
 
 pub fn execute_create_infusion(
@@ -294,17 +569,31 @@ pub fn execute_create_infusion(
     let mut msgs = Vec::new();
     let mut fee_msgs: Vec<CosmosMsg<Empty>> = Vec::new();
     let mut attrs = vec![];
+    let mut events: Vec<Event> = vec![];
 
     if cfg.max_infusions < infusions.len() as u64 {
         return Err(ContractError::MaxInfusionsError {});
     }
 
     let collection_checksum = cfg.code_hash.clone();
-    let salt1 = generate_instantiate_salt2(
-        &collection_checksum,
-        env.block.height,
-        info.sender.as_bytes(),
-    );
+
+    // assert creation fees, charged once per infusion. admin skips creation fees
+    let mut funds = info.funds.clone();
+    if let Some(creation_fee) = cfg.min_creation_fee.as_ref().map(Asset::to_coin) {
+        if info.sender != cfg.contract_owner {
+            let count = infusions.len() as u128;
+            let accepted: Vec<Coin> = cfg
+                .accepted_creation_fees
+                .iter()
+                .map(Asset::to_coin)
+                .collect();
+            let fee = charge_creation_fee(&creation_fee, &accepted, count, &mut funds)?;
+            fee_msgs.push(CosmosMsg::Bank(BankMsg::Send {
+                to_address: cfg.contract_owner.to_string(),
+                amount: vec![fee],
+            }));
+        }
+    }
 
     // loop through each infusion
     for infusion in infusions {
@@ -317,34 +606,85 @@ pub fn execute_create_infusion(
             return Err(ContractError::MetadataArrayLengthError);
         }
 
-        // assert creation fees
-        if let Some(creation_fee) = cfg.min_creation_fee.clone() {
-            if info.sender == cfg.contract_owner {
-                // skip over creation fees for admin
-            } else if info.funds.iter().any(|e| e == &creation_fee) {
-                let base_fee = CosmosMsg::Bank(BankMsg::Send {
-                    to_address: cfg.contract_owner.to_string(),
-                    amount: vec![creation_fee],
+        let fee_recipients = match infusion.fee_recipients {
+            Some(recipients) => validate_fee_recipients(deps.api, recipients)?,
+            None => vec![],
+        };
+        let payment_recipient = match infusion.payment_recipient {
+            Some(recipient) => deps.api.addr_validate(recipient.as_str())?,
+            None => info.sender.clone(),
+        };
+
+        for col in &infusion.collections {
+            if let Some(ps) = &col.payment_substitute {
+                ps.validate(deps.api)?;
+            }
+        }
+        if let Some(pricing) = &infusion.infusion_params.pricing {
+            validate_pricing(
+                deps.api,
+                pricing,
+                infusion.infusion_params.mint_fee.as_ref(),
+            )?;
+        }
+        if infusion
+            .infusion_params
+            .referral_share
+            .is_some_and(|share| share.is_zero() || share > Decimal::one())
+        {
+            return Err(ContractError::InvalidReferralShare {});
+        }
+        validate_fee_shares(&infusion.collections, &infusion.infusion_params)?;
+        for rule in &infusion.infusion_params.fee_discounts {
+            deps.api.addr_validate(rule.collection.as_str())?;
+            if !(1..=MAX_DISCOUNT_MIN_HELD).contains(&rule.min_held)
+                || rule.discount.is_zero()
+                || rule.discount > Decimal::one()
+            {
+                return Err(ContractError::InvalidFeeDiscount {
+                    collection: rule.collection.to_string(),
+                });
+            }
+        }
+        for ingredient in &infusion.infusion_params.fungible_ingredients {
+            ingredient.min_req.validate(deps.api)?;
+            let min = ingredient.min_req.amount();
+            if min.is_zero() || ingredient.max_req.is_some_and(|max| max < min) {
+                return Err(ContractError::InvalidFungibleIngredient {
+                    denom: ingredient.min_req.denom(),
                 });
-                fee_msgs.push(base_fee);
-            } else {
-                return Err(ContractError::RequirednfusionFeeError { fee: creation_fee });
+            }
+        }
+
+        // assert vault address
+        if let IngredientDisposition::Vault { addr } = &infusion.infusion_params.disposition {
+            deps.api.addr_validate(addr.as_str())?;
+        }
+        // redemption requires nfts to be locked or vaulted rather than burned
+        if let Some(redemption) = &infusion.infusion_params.redemption {
+            if infusion.infusion_params.disposition == IngredientDisposition::Burn {
+                return Err(ContractError::RedemptionRequiresEscrow);
+            }
+            if let Some(fee) = &redemption.fee {
+                fee.validate(deps.api)?;
             }
         }
 
         // assert fees being set
         if let Some(mf) = infusion.infusion_params.mint_fee.clone() {
-            if !mf.amount.is_zero() {
+            mf.validate(deps.api)?;
+            if !mf.amount().is_zero() {
                 if cfg
                     .min_infusion_fee
                     .clone()
-                    .is_none_or(|f| f.amount <= mf.amount)
+                    .is_none_or(|f| f.amount() <= mf.amount())
                 {
                 } else {
                     return Err(ContractError::InfusionFeeLessThanMinimumRequired {
                         min: cfg
                             .min_infusion_fee
-                            .expect("should never be empty if errors"),
+                            .expect("should never be empty if errors")
+                            .to_coin(),
                     });
                 }
             } else {
@@ -356,6 +696,13 @@ pub fn execute_create_infusion(
         let infusion_id: u64 = cfg.latest_infusion_id + 1;
         cfg.latest_infusion_id = infusion_id;
 
+        // salt includes the infusion id, giving each infusion created at once its own address
+        let salt1 = generate_instantiate_salt2(
+            &collection_checksum,
+            env.block.height,
+            &[info.sender.as_bytes(), &infusion_id.to_be_bytes()].concat(),
+        );
+
         validate_eligible_collection_list(
             deps.storage,
             // deps.querier,
@@ -469,14 +816,15 @@ pub fn execute_create_infusion(
                 ..infusion.infused_collection
             },
             infusion_params: infusion.infusion_params,
-            payment_recipient: infusion.payment_recipient.unwrap_or(info.sender.clone()),
+            payment_recipient,
+            fee_recipients,
             owner: infusion.owner.unwrap_or(info.sender.clone()),
             enabled: true,
         };
 
         // saves the infusion bundle to state with (infused_collection, id)
         let key = (infusion_collection_addr_human.clone(), infusion_id);
-        INFUSION.save(deps.storage, key.clone(), &infusion_config)?;
+        infusions().save(deps.storage, key.clone(), &infusion_config)?;
         INFUSION_ID.save(deps.storage, infusion_id, &key)?;
         // contribute to contract randomness
         let mc = MINT_COUNT.load(deps.storage).unwrap_or_default() + 1;
@@ -492,12 +840,66 @@ pub fn execute_create_infusion(
 
         msgs.push(init_infusion_msg);
         attrs.push(Attribute::new("infusion-id", infusion_id.to_string()));
+        events.push(
+            CreateEvent {
+                infusion_id,
+                owner: &infusion_config.owner,
+                infused_collection: infusion_collection_addr_human.as_str(),
+                payment_recipient: &infusion_config.payment_recipient,
+                num_tokens: infusion_config.infused_collection.num_tokens,
+                collections: infusion_config
+                    .collections
+                    .iter()
+                    .map(|c| &c.addr)
+                    .collect(),
+            }
+            .into(),
+        );
+    }
+    let fee_split = FeeSplitEvent::from_msgs(None, "creation", &fee_msgs);
+    if !fee_split.payments.is_empty() {
+        events.push(fee_split.into());
     }
 
+    let fee_msgs = route_transfers(settle_fees(deps.storage, deps.api, &cfg, fee_msgs)?)?;
     Ok(Response::new()
         .add_messages(msgs)
         .add_messages(fee_msgs)
-        .add_attributes(attrs))
+        .add_messages(refund_msg(&info.sender, funds)?)
+        .add_attributes(attrs)
+        .add_events(events))
+}
+
+/// Deducts `count` creation fees from `funds`, in the first accepted denom sent in full.
+fn charge_creation_fee(
+    creation_fee: &Coin,
+    accepted: &[Coin],
+    count: u128,
+    funds: &mut [Coin],
+) -> Result<Coin, ContractError> {
+    for fee in std::iter::once(creation_fee).chain(accepted) {
+        let total = fee
+            .amount
+            .checked_mul(count.into())
+            .map_err(StdError::from)?;
+        if let Some(paid) = funds
+            .iter_mut()
+            .find(|c| c.denom == fee.denom && c.amount >= total)
+        {
+            paid.amount -= total;
+            return Ok(coin(total.u128(), fee.denom.clone()));
+        }
+    }
+    Err(ContractError::RequirednfusionFeeError {
+        fee: coin(
+            creation_fee
+                .amount
+                .checked_mul(count.into())
+                .map_err(StdError::from)?
+                .u128(),
+            creation_fee.denom.clone(),
+        ),
+    })
 }
 
 /// Performs various validations on an infusions eligilbe collections being set. If triggered via config update,
@@ -590,9 +992,7 @@ fn validate_eligible_collection_list(
                     1 => {}
                     _ => {
                         if unique_feesub.contains(&fs) {
-                            return Err(ContractError::DuplicateFeeSubToken {
-                                token: fs.denom.clone(),
-                            });
+                            return Err(ContractError::DuplicateFeeSubToken { token: fs.denom() });
                         }
                     }
                 }
@@ -604,6 +1004,7 @@ fn validate_eligible_collection_list(
         unique.push(col.addr.clone());
 
         match ELIGIBLE_COLLECTION.may_load(storage, addr)? {
+            Some(e) if e.contains(&infusion_id) => {}
             Some(mut e) => ELIGIBLE_COLLECTION.save(storage, addr, {
                 e.push(infusion_id);
                 &e
@@ -665,63 +1066,160 @@ fn execute_infuse_bundle(
     info: MessageInfo,
     infusion_id: u64,
     bundle: Vec<Bundle>,
+    recipient: Option<String>,
+    referrer: Option<String>,
 ) -> Result<Response, ContractError> {
     let cfg = CONFIG.load(deps.storage)?;
+    // infused tokens are minted to the recipient, if one is provided
+    let recipient = match recipient {
+        Some(r) => deps.api.addr_validate(&r)?,
+        None => info.sender.clone(),
+    };
+    let referrer = validate_referrer(deps.api, &info.sender, referrer)?;
+    // assert ownership
+    assert_bundle_ownership(deps.querier, &info.sender, &bundle)?;
+    let mut funds = info.funds.clone();
+
+    let (response_msgs, events) = infuse_bundles(
+        deps,
+        &env,
+        &cfg,
+        &info.sender,
+        &recipient,
+        referrer.as_ref(),
+        infusion_id,
+        bundle,
+        &mut funds,
+    )?;
+
+    Ok(Response::new()
+        .add_messages(response_msgs)
+        .add_messages(refund_msg(&info.sender, funds)?)
+        .add_events(events))
+}
+
+/// Validates an optional referrer, who may not be the sender.
+fn validate_referrer(
+    api: &dyn Api,
+    sender: &Addr,
+    referrer: Option<String>,
+) -> Result<Option<Addr>, ContractError> {
+    let referrer = referrer.map(|r| api.addr_validate(&r)).transpose()?;
+    if referrer.as_ref() == Some(sender) {
+        return Err(ContractError::InvalidReferrer {});
+    }
+    Ok(referrer)
+}
+
+/// Infuse bundles into multiple infusions at once. Funds sent are consumed by each item in order,
+/// with the whole message failing if any single item cannot be infused.
+fn execute_infuse_many(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    items: Vec<(u64, Vec<Bundle>)>,
+) -> Result<Response, ContractError> {
+    if items.is_empty() {
+        return Err(ContractError::EmptyBundle);
+    }
+    let cfg = CONFIG.load(deps.storage)?;
+    let mut funds = info.funds.clone();
+    let mut response_msgs: Vec<CosmosMsg> = Vec::new();
+    let mut events = Vec::new();
+
+    for (index, (infusion_id, bundle)) in items.into_iter().enumerate() {
+        let (msgs, item_events) = assert_bundle_ownership(deps.querier, &info.sender, &bundle)
+            .and_then(|_| {
+                infuse_bundles(
+                    deps.branch(),
+                    &env,
+                    &cfg,
+                    &info.sender,
+                    &info.sender,
+                    None,
+                    infusion_id,
+                    bundle,
+                    &mut funds,
+                )
+            })
+            .map_err(|err| ContractError::InfuseManyItemFailed {
+                index: index as u64,
+                id: infusion_id,
+                err: err.to_string(),
+            })?;
+        response_msgs.extend(msgs);
+        events.extend(item_events);
+    }
+
+    Ok(Response::new()
+        .add_messages(response_msgs)
+        .add_messages(refund_msg(&info.sender, funds)?)
+        .add_events(events))
+}
+
+/// Validates & forms the msgs to infuse bundles for a single infusion.
+/// Any mint fee or payment substitutes required are deducted from `funds`.
+/// Ownership of the nfts in each bundle must be asserted by the caller.
+#[allow(clippy::too_many_arguments)]
+fn infuse_bundles(
+    deps: DepsMut,
+    env: &Env,
+    cfg: &Config,
+    sender: &Addr,
+    recipient: &Addr,
+    referrer: Option<&Addr>,
+    infusion_id: u64,
+    bundle: Vec<Bundle>,
+    funds: &mut Vec<Coin>,
+) -> Result<(Vec<CosmosMsg>, Vec<Event>), ContractError> {
     let key = INFUSION_ID.load(deps.storage, infusion_id)?;
-    let infusion = INFUSION.load(deps.storage, key)?;
+    let infusion = infusions().load(deps.storage, key)?;
 
     if !infusion.enabled {
         return Err(ContractError::InfusionIsEnded {});
     }
 
-    let res = Response::new();
-    let sender = info.sender.clone();
-    let querier = deps.querier;
-
-    let mut funds = info.funds.clone();
     let mut response_msgs: Vec<CosmosMsg> = Vec::new();
     let mut mc = 0u64;
+    let mut minted: Vec<u32> = Vec::new();
+    let consumed: Vec<NFT> = bundle.iter().flat_map(|b| b.nfts.clone()).collect();
+    let bundle_count = bundle.len() as u64;
 
     // first, any fee parameters are validated
-    if let Some(fee) = infusion.infusion_params.mint_fee.clone() {
-        if info.sender == infusion.owner {
-            // infusion owner omitted from fee payment
-        } else {
-            let mut fee_error = None;
-            funds
-                .iter_mut()
-                .filter(|a| a.denom == fee.denom)
-                .for_each(|a: &mut Coin| {
-                    if a.amount < fee.amount {
-                        fee_error = Some(ContractError::FeeNotAccepted {
-                            have: a.clone(),
-                            want: fee.clone(),
-                        });
-                    } else {
-                        a.amount -= fee.amount;
-                    }
-                });
-            if let Some(e) = fee_error {
-                return Err(e);
-            } else if funds.len() > 0 {
-                let fee_msgs = form_feesplit_helper(
-                    cfg.owner_fee,
-                    cfg.contract_owner.to_string(),
-                    infusion.payment_recipient.to_string(),
-                    fee,
-                )?;
-                response_msgs.extend(fee_msgs);
-            }
-        }
+    let (mint_fee, shares, royalty_events) = mint_fee_and_shares(
+        deps.as_ref(),
+        env.block.time,
+        &infusion,
+        sender,
+        referrer,
+        &consumed,
+    )?;
+    let (fee_msgs, mut fee_paid) =
+        charge_mint_fee(cfg, &infusion, sender, &shares, mint_fee.clone(), funds)?;
+    let mint_fee_paid = fee_paid.is_some();
+    let fee_retained = fee_paid
+        .as_ref()
+        .map(|fee| split_fee_shares(fee, &shares).map(|(_, retained)| retained))
+        .transpose()?;
+    // fees counted toward the infusion stats, including the payment substitutes consumed
+    let mut stats_fees: Vec<Coin> = fee_retained.into_iter().collect();
+    if let (Some(referrer), Some(fee), Some(_)) =
+        (referrer, &fee_paid, infusion.infusion_params.referral_share)
+    {
+        record_referral(deps.storage, referrer, referral_reward(&infusion, fee))?;
     }
+    response_msgs.extend(fee_msgs);
 
     // // check lens
     if bundle.is_empty() {
         if infusion.infusion_params.wavs_enabled {
-            let burn = check_bundles(deps.storage, &cfg, &infusion, &sender, vec![], &funds)?;
+            let before = funds.clone();
+            let burn = check_bundles(deps.storage, cfg, &infusion, sender, vec![], funds)?;
             if burn.0.is_empty() {
                 return Err(ContractError::EmptyBundle);
             }
+            let spent = consumed_funds(&before, funds);
+            stats_fees.extend(consumed_funds(&spent, &burn.2));
             let prep_msgs = prepare_wasm_events(
                 env.clone(),
                 deps.storage,
@@ -735,8 +1233,20 @@ fn execute_infuse_bundle(
                 infusion.infused_collection.base_uri.clone(),
                 infusion_id,
                 burn.1,
-                &sender,
+                sender,
+                recipient,
             )?;
+            record_provenance(
+                deps.storage,
+                env,
+                infusion_id,
+                sender,
+                &[],
+                &prep_msgs.2,
+                spent,
+                fee_paid.take(),
+            )?;
+            minted.extend(prep_msgs.2);
             response_msgs.extend(burn.0);
             response_msgs.extend(prep_msgs.0);
             // check if we can satisfy burnt
@@ -747,76 +1257,1027 @@ fn execute_infuse_bundle(
 
     // for each nft collection bundle sent to infuse
     for bundle in bundle {
-        // assert ownership
-        is_nft_owner(querier, sender.clone(), bundle.nfts.clone())?;
         // add each burn nft & mint infused token to response
+        let before = funds.clone();
         let burn = burn_bundle(
             deps.storage,
             env.clone(),
-            &cfg,
-            bundle.nfts,
-            &sender,
+            cfg,
+            bundle.nfts.clone(),
+            sender,
+            recipient,
             &infusion,
             infusion_id,
-            &funds,
+            funds,
         )?;
         // println!("burn: {:#?}", burn);
+        let spent = consumed_funds(&before, funds);
+        stats_fees.extend(consumed_funds(&spent, &burn.3));
+        record_provenance(
+            deps.storage,
+            env,
+            infusion_id,
+            sender,
+            &bundle.nfts,
+            &burn.2,
+            spent,
+            fee_paid.take(),
+        )?;
+        minted.extend(burn.2);
         response_msgs.extend(burn.0);
         mc += burn.1;
     }
+    assert_mint_fee_paid(&infusion, sender, mint_fee, mint_fee_paid)?;
     MINT_COUNT.save(deps.storage, &mc)?;
+    record_infusion_stats(
+        deps.storage,
+        infusion_id,
+        sender,
+        &consumed,
+        minted.len() as u64,
+        stats_fees,
+    )?;
+
+    let infused_collection = infusion.infused_collection.addr.clone().unwrap_or_default();
+    let mut events: Vec<Event> = vec![InfuseEvent {
+        infusion_id,
+        sender,
+        recipient,
+        bundles: bundle_count,
+        minted: minted.len() as u64,
+    }
+    .into()];
+    events.extend(royalty_events);
+    events.extend(consumed.iter().map(|nft| {
+        Event::from(BurnEvent {
+            infusion_id,
+            burner: sender,
+            nft,
+            disposition: &infusion.infusion_params.disposition,
+        })
+    }));
+    events.extend(minted.iter().map(|token_id| {
+        Event::from(MintEvent {
+            infusion_id,
+            infused_collection: &infused_collection,
+            token_id: *token_id,
+            recipient,
+        })
+    }));
+    let response_msgs = collapse_bank_sends(response_msgs);
+    let fee_split = FeeSplitEvent::from_msgs(Some(infusion_id), "mint", &response_msgs);
+    if !fee_split.payments.is_empty() {
+        events.push(fee_split.into());
+    }
+    let response_msgs = route_transfers(settle_fees(deps.storage, deps.api, cfg, response_msgs)?)?;
+
+    Ok((response_msgs, events))
+}
+
+/// Returns the mint fee owed by `sender`, along with the royalty & referral shares paid from it.
+/// Royalties skipped for lack of a valid recipient are returned as events.
+fn mint_fee_and_shares(
+    deps: Deps,
+    now: Timestamp,
+    infusion: &InfusionState,
+    sender: &Addr,
+    referrer: Option<&Addr>,
+    consumed: &[NFT],
+) -> Result<(Option<Coin>, Vec<(Addr, Decimal)>, Vec<Event>), ContractError> {
+    let mint_fee = apply_fee_discount(
+        deps.querier,
+        infusion,
+        sender,
+        current_mint_fee(deps, now, infusion)?,
+    );
+    let (mut shares, royalty_events) = source_royalties(deps, infusion, consumed)?;
+    if let (Some(referrer), Some(share)) = (referrer, infusion.infusion_params.referral_share) {
+        shares.push((referrer.clone(), share));
+    }
+    Ok((mint_fee, shares, royalty_events))
+}
+
+/// Updates the running stats of an infusion after an infuse.
+/// `fees` are the mint fee retained after royalty & referral shares, & payment substitutes used.
+fn record_infusion_stats(
+    storage: &mut dyn Storage,
+    infusion_id: u64,
+    burner: &Addr,
+    nfts: &[NFT],
+    minted: u64,
+    fees: Vec<Coin>,
+) -> StdResult<()> {
+    let mut stats = INFUSION_STATS
+        .may_load(storage, infusion_id)?
+        .unwrap_or_default();
+    stats.infuse_count += 1;
+    let key = (infusion_id, burner.clone());
+    let mut burner_record = match burner_stats().may_load(storage, key.clone())? {
+        Some(record) => record,
+        None => {
+            stats.unique_burners += 1;
+            BurnerStats {
+                infusion_id,
+                burns: 0,
+                mints: 0,
+            }
+        }
+    };
+    burner_record.burns += nfts.len() as u64;
+    burner_record.mints += minted;
+    burner_stats().save(storage, key, &burner_record)?;
+    for nft in nfts {
+        match stats.burned.iter_mut().find(|b| b.addr == nft.addr) {
+            Some(b) => b.count += 1,
+            None => stats.burned.push(CollectionBurnCount {
+                addr: nft.addr.clone(),
+                count: 1,
+            }),
+        }
+    }
+    for fee in fees.into_iter().filter(|fee| !fee.amount.is_zero()) {
+        match stats.fees.iter_mut().find(|c| c.denom == fee.denom) {
+            Some(c) => c.amount += fee.amount,
+            None => stats.fees.push(fee),
+        }
+    }
+    INFUSION_STATS.save(storage, infusion_id, &stats)
+}
+
+/// Handles cw20 tokens sent to the infuser with `Send`, tracking them as funds of the cw20 sender.
+fn execute_receive_cw20(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    // the hook sender is only trusted from tokens the infuser expects, & pays with those alone
+    if !info.funds.is_empty() {
+        return Err(ContractError::Cw20WithNativeFunds {});
+    }
+    let token = info.sender;
+    let receive: ReceiveCw20Msg = from_json(&msg.msg)?;
+    let accepted = match &receive {
+        ReceiveCw20Msg::Infuse { id, .. } => {
+            let key = INFUSION_ID.load(deps.storage, *id)?;
+            accepts_cw20(&infusions().load(deps.storage, key)?, &token)
+        }
+        ReceiveCw20Msg::Uninfuse { id, .. } => {
+            let key = INFUSION_ID.load(deps.storage, *id)?;
+            let infusion = infusions().load(deps.storage, key)?;
+            infusion
+                .infusion_params
+                .redemption
+                .and_then(|r| r.fee)
+                .is_some_and(|fee| is_cw20(&fee, &token))
+        }
+        ReceiveCw20Msg::CreateInfusion { .. } => {
+            let cfg = CONFIG.load(deps.storage)?;
+            cfg.min_creation_fee
+                .iter()
+                .chain(&cfg.accepted_creation_fees)
+                .any(|fee| is_cw20(fee, &token))
+        }
+    };
+    if !accepted {
+        return Err(ContractError::Cw20NotAccepted {
+            token: token.to_string(),
+        });
+    }
+    let info = MessageInfo {
+        sender: deps.api.addr_validate(&msg.sender)?,
+        funds: vec![Asset::Cw20 {
+            address: token,
+            amount: msg.amount,
+        }
+        .to_coin()],
+    };
+    match receive {
+        ReceiveCw20Msg::Infuse {
+            id,
+            bundle,
+            recipient,
+            referrer,
+        } => execute_infuse_bundle(deps, env, info, id, bundle, recipient, referrer),
+        ReceiveCw20Msg::Uninfuse { id, token_id } => execute_uninfuse(deps, info, id, token_id),
+        ReceiveCw20Msg::CreateInfusion { infusions } => {
+            execute_create_infusion(deps, info, env, infusions)
+        }
+    }
+}
 
-    Ok(res.add_messages(response_msgs))
+/// Returns whether `asset` is an amount of the cw20 `token`.
+fn is_cw20(asset: &Asset, token: &Addr) -> bool {
+    matches!(asset, Asset::Cw20 { address, .. } if address == token)
+}
+
+/// Returns whether `token` is a cw20 an infusion is paid with: its mint fee,
+/// a payment substitute or a fungible ingredient.
+fn accepts_cw20(infusion: &InfusionState, token: &Addr) -> bool {
+    let params = &infusion.infusion_params;
+    params.mint_fee.iter().any(|fee| is_cw20(fee, token))
+        || infusion
+            .collections
+            .iter()
+            .filter_map(|col| col.payment_substitute.as_ref())
+            .any(|ps| is_cw20(ps, token))
+        || params
+            .fungible_ingredients
+            .iter()
+            .any(|f| is_cw20(&f.min_req, token))
+}
+
+/// Adds an nft sent to the infuser with `SendNft` to the senders pending bundle for an infusion.
+fn execute_receive_nft(
+    deps: DepsMut,
+    info: MessageInfo,
+    msg: Cw721ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let ReceiveNftMsg::AddToBundle { id } = from_json(&msg.msg)?;
+    let cfg = CONFIG.load(deps.storage)?;
+    let key = INFUSION_ID.load(deps.storage, id)?;
+    let infusion = infusions().load(deps.storage, key)?;
+
+    if !infusion.enabled {
+        return Err(ContractError::InfusionIsEnded {});
+    }
+    // the nft collection calling this hook must be eligible for the infusion
+    if !infusion.collections.iter().any(|c| c.addr == info.sender) {
+        return Err(ContractError::NftIsNotEligible {
+            col: info.sender.to_string(),
+        });
+    }
+
+    let depositor = deps.api.addr_validate(&msg.sender)?;
+    let token_id = msg
+        .token_id
+        .parse::<u64>()
+        .map_err(|_| StdError::generic_err(format!("invalid token id: {}", msg.token_id)))?;
+
+    // pending nfts are completed as a single bundle
+    let mut pending = PENDING_BUNDLES
+        .may_load(deps.storage, (&depositor, id))?
+        .unwrap_or_default();
+    if pending.len() as u64 >= cfg.max_per_bundle {
+        return Err(ContractError::PendingBundleFull {
+            max: cfg.max_per_bundle,
+        });
+    }
+    let nft = NFT {
+        addr: info.sender.clone(),
+        token_id,
+    };
+    pending.push(nft.clone());
+    PENDING_BUNDLES.save(deps.storage, (&depositor, id), &pending)?;
+
+    Ok(Response::new().add_event(PendingBundleEvent {
+        infusion_id: id,
+        depositor: &depositor,
+        kind: "add",
+        nfts: &[nft],
+    }))
+}
+
+/// Infuses the nfts held by the infuser in the senders pending bundle for an infusion.
+fn execute_complete_bundle(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    infusion_id: u64,
+    recipient: Option<String>,
+    referrer: Option<String>,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+    let recipient = match recipient {
+        Some(r) => deps.api.addr_validate(&r)?,
+        None => info.sender.clone(),
+    };
+    let referrer = validate_referrer(deps.api, &info.sender, referrer)?;
+    let pending = PENDING_BUNDLES
+        .may_load(deps.storage, (&info.sender, infusion_id))?
+        .unwrap_or_default();
+    if pending.is_empty() {
+        return Err(ContractError::EmptyBundle);
+    }
+    PENDING_BUNDLES.remove(deps.storage, (&info.sender, infusion_id));
+
+    // nfts are already owned by the infuser, so no ownership assertion is needed
+    let mut funds = info.funds.clone();
+    let (response_msgs, events) = infuse_bundles(
+        deps,
+        &env,
+        &cfg,
+        &info.sender,
+        &recipient,
+        referrer.as_ref(),
+        infusion_id,
+        vec![Bundle { nfts: pending }],
+        &mut funds,
+    )?;
+
+    Ok(Response::new()
+        .add_messages(response_msgs)
+        .add_messages(refund_msg(&info.sender, funds)?)
+        .add_events(events))
+}
+
+/// Returns any nfts in the senders pending bundle for an infusion back to the sender.
+fn execute_withdraw_pending_bundle(
+    deps: DepsMut,
+    info: MessageInfo,
+    infusion_id: u64,
+) -> Result<Response, ContractError> {
+    let pending = PENDING_BUNDLES
+        .may_load(deps.storage, (&info.sender, infusion_id))?
+        .unwrap_or_default();
+    if pending.is_empty() {
+        return Err(ContractError::EmptyBundle);
+    }
+    PENDING_BUNDLES.remove(deps.storage, (&info.sender, infusion_id));
+
+    let mut msgs: Vec<CosmosMsg> = Vec::new();
+    for nft in &pending {
+        msgs.push(into_cosmos_msg(
+            cw721::Cw721ExecuteMsg::TransferNft {
+                recipient: info.sender.to_string(),
+                token_id: nft.token_id.to_string(),
+            },
+            nft.addr.clone(),
+            None,
+        )?);
+    }
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_event(PendingBundleEvent {
+            infusion_id,
+            depositor: &info.sender,
+            kind: "withdraw",
+            nfts: &pending,
+        }))
+}
+
+/// Burns an infused token, returning the escrowed nfts used to create it to its owner.
+/// The infuser must be approved for the infused token, & by the vault for vaulted nfts.
+fn execute_uninfuse(
+    deps: DepsMut,
+    info: MessageInfo,
+    infusion_id: u64,
+    token_id: u32,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+    let key = INFUSION_ID.load(deps.storage, infusion_id)?;
+    let infusion = infusions().load(deps.storage, key)?;
+    let redemption = infusion
+        .infusion_params
+        .redemption
+        .clone()
+        .ok_or(ContractError::RedemptionDisabled)?;
+    let infused_col = infusion
+        .infused_collection
+        .addr
+        .clone()
+        .expect("no-infusion-collection");
+
+    // only the owner of the infused token may redeem it
+    let owner_response: OwnerOfResponse = deps.querier.query_wasm_smart(
+        infused_col.clone(),
+        &Cw721QueryMsg::OwnerOf {
+            token_id: token_id.to_string(),
+            include_expired: None,
+        },
+    )?;
+    if owner_response.owner != info.sender.to_string() {
+        return Err(ContractError::SenderNotOwner {});
+    }
+
+    let ingredients = INFUSED_INGREDIENTS
+        .may_load(deps.storage, (infusion_id, token_id))?
+        .ok_or(ContractError::NothingToRedeem { token_id })?;
+    INFUSED_INGREDIENTS.remove(deps.storage, (infusion_id, token_id));
+
+    let mut msgs: Vec<CosmosMsg> = vec![into_cosmos_msg(
+        cw721::Cw721ExecuteMsg::Burn {
+            token_id: token_id.to_string(),
+        },
+        infused_col.clone(),
+        None,
+    )?];
+
+    let mut funds = info.funds.clone();
+    if let Some(fee) = redemption.fee.as_ref().map(Asset::to_coin) {
+        if info.sender != infusion.owner {
+            let have = funds
+                .iter_mut()
+                .find(|c| c.denom == fee.denom)
+                .ok_or_else(|| ContractError::FeeNotAccepted {
+                    have: coin(0, fee.denom.clone()),
+                    want: fee.clone(),
+                })?;
+            if have.amount < fee.amount {
+                return Err(ContractError::FeeNotAccepted {
+                    have: have.clone(),
+                    want: fee,
+                });
+            }
+            have.amount -= fee.amount;
+            msgs.extend(form_feesplit_helper(
+                cfg.owner_fee,
+                cfg.contract_owner.to_string(),
+                &infusion,
+                fee,
+            )?);
+        }
+    }
+
+    // escrowed nfts are either returned or burned, clearing their escrow record either way.
+    // vaulted nfts are moved by the infuser as an operator approved by the vault
+    let (mut returned_nfts, mut burned_nfts) = (vec![], vec![]);
+    for nft in &ingredients {
+        let returned = redemption
+            .collections
+            .as_ref()
+            .is_none_or(|cols| cols.contains(&nft.addr));
+        if returned {
+            returned_nfts.push(nft);
+        } else {
+            burned_nfts.push(nft);
+        }
+        let token_id = nft.token_id.to_string();
+        msgs.push(if returned {
+            into_cosmos_msg(
+                cw721::Cw721ExecuteMsg::TransferNft {
+                    recipient: info.sender.to_string(),
+                    token_id,
+                },
+                nft.addr.clone(),
+                None,
+            )?
+        } else {
+            into_cosmos_msg(
+                cw721::Cw721ExecuteMsg::Burn { token_id },
+                nft.addr.clone(),
+                None,
+            )?
+        });
+        ESCROWED_NFTS.remove(deps.storage, (&nft.addr, nft.token_id));
+    }
+
+    let mut res = Response::new().add_event(UninfuseEvent {
+        infusion_id,
+        owner: &info.sender,
+        infused_collection: infused_col.as_str(),
+        token_id,
+        returned: returned_nfts,
+        burned: burned_nfts,
+    });
+    let fee_split = FeeSplitEvent::from_msgs(Some(infusion_id), "redemption", &msgs);
+    if !fee_split.payments.is_empty() {
+        res = res.add_event(fee_split);
+    }
+    let msgs = route_transfers(settle_fees(deps.storage, deps.api, &cfg, msgs)?)?;
+
+    Ok(res
+        .add_messages(msgs)
+        .add_messages(refund_msg(&info.sender, funds)?))
 }
 
 /// checks all bundles nfts, determines how many nfts to mint,
-/// returns msgs to burn, mint nfts, & transfer any fee substitute funds to their destination.
+/// returns msgs to burn, mint nfts, & transfer any fee substitute funds to their destination,
+/// along with the token ids minted & fungible ingredients burned.
 fn burn_bundle(
     storage: &mut dyn Storage,
     env: Env,
     cfg: &Config,
     nfts: Vec<NFT>,
     sender: &Addr,
+    recipient: &Addr,
     infusion: &InfusionState,
     infusion_id: u64,
-    funds: &Vec<Coin>,
-) -> Result<(Vec<CosmosMsg>, u64), ContractError> {
+    funds: &mut Vec<Coin>,
+) -> Result<(Vec<CosmosMsg>, u64, Vec<u32>, Vec<Coin>), ContractError> {
     let mut msgs: Vec<CosmosMsg> = Vec::new();
     // confirm bundle is in current infusion, and expected amount sent
     let paysub_msg_and_mc = check_bundles(storage, cfg, infusion, sender, nfts.clone(), funds)?;
     // println!("paysub_msg_and_mc: {:#?}", paysub_msg_and_mc);
     // println!("mint_num: {:#?}", mint_num);
-    for nft in nfts {
-        msgs.push(into_cosmos_msg(
-            cw721::Cw721ExecuteMsg::Burn {
-                token_id: nft.token_id.to_string(),
+    for nft in nfts.clone() {
+        msgs.push(ingredient_disposition_msg(
+            storage,
+            &env,
+            &infusion.infusion_params.disposition,
+            infusion_id,
+            sender,
+            nft,
+        )?);
+    }
+
+    let prep_msgs = prepare_wasm_events(
+        env.clone(),
+        storage,
+        &Addr::unchecked(
+            infusion
+                .infused_collection
+                .addr
+                .clone()
+                .expect("no-infusion-collection"),
+        ),
+        infusion.infused_collection.base_uri.clone(),
+        infusion_id,
+        paysub_msg_and_mc.1,
+        sender,
+        recipient,
+    )?;
+    msgs.extend(prep_msgs.0);
+    msgs.extend(paysub_msg_and_mc.0);
+
+    // record the escrowed nfts used to mint each infused token, so they may be redeemed.
+    // tokens minted past the nfts consumed have nothing to redeem
+    if infusion.infusion_params.disposition != IngredientDisposition::Burn {
+        for (token_id, ingredients) in split_ingredients(&nfts, &prep_msgs.2) {
+            if !ingredients.is_empty() {
+                INFUSED_INGREDIENTS.save(
+                    storage,
+                    (infusion_id, token_id),
+                    &ingredients.to_vec(),
+                )?;
+            }
+        }
+    }
+    // Return the final mint count which has been properly incremented for each token ID
+    Ok((msgs, prep_msgs.1, prep_msgs.2, paysub_msg_and_mc.2))
+}
+
+/// Returns the mint fee currently required by an infusion, following its pricing strategy.
+fn current_mint_fee(
+    deps: Deps,
+    now: Timestamp,
+    infusion: &InfusionState,
+) -> StdResult<Option<Coin>> {
+    let Some(fee) = infusion
+        .infusion_params
+        .mint_fee
+        .as_ref()
+        .map(Asset::to_coin)
+    else {
+        return Ok(None);
+    };
+    let Some(pricing) = &infusion.infusion_params.pricing else {
+        return Ok(Some(fee));
+    };
+    let total = infusion.infused_collection.num_tokens;
+    let remaining = match &infusion.infused_collection.addr {
+        Some(addr) => MINTABLE_NUM_TOKENS
+            .may_load(deps.storage, addr.clone())?
+            .unwrap_or(total),
+        None => total,
+    };
+    let price = pricing.price(
+        deps.querier,
+        &fee.denom,
+        fee.amount,
+        now,
+        total.saturating_sub(remaining),
+        total,
+    )?;
+    Ok(Some(coin(price.u128(), fee.denom)))
+}
+
+/// Applies the largest holder discount the sender qualifies for to the mint fee.
+/// Holdings are verified with cw721 `Tokens` queries; a collection failing to answer grants
+/// no discount rather than blocking the infuse.
+fn apply_fee_discount(
+    querier: QuerierWrapper,
+    infusion: &InfusionState,
+    sender: &Addr,
+    mint_fee: Option<Coin>,
+) -> Option<Coin> {
+    let fee = mint_fee?;
+    if *sender == infusion.owner {
+        return Some(fee);
+    }
+    let mut discount = Decimal::zero();
+    for rule in &infusion.infusion_params.fee_discounts {
+        if rule.discount <= discount {
+            continue;
+        }
+        let held = querier
+            .query_wasm_smart::<cw721::TokensResponse>(
+                rule.collection.to_string(),
+                &Cw721QueryMsg::Tokens {
+                    owner: sender.to_string(),
+                    start_after: None,
+                    limit: Some(rule.min_held),
+                },
+            )
+            .map_or(0, |res| res.tokens.len());
+        if held >= rule.min_held as usize {
+            discount = rule.discount;
+        }
+    }
+    let amount = fee.amount * (Decimal::one() - discount);
+    if amount.is_zero() {
+        return None;
+    }
+    Some(coin(amount.u128(), fee.denom))
+}
+
+/// Validates a pricing strategy against the mint fee it prices.
+fn validate_pricing(
+    api: &dyn Api,
+    pricing: &PricingStrategy,
+    mint_fee: Option<&Asset>,
+) -> Result<(), ContractError> {
+    let invalid = |reason: &str| {
+        Err(ContractError::InvalidPricingStrategy {
+            reason: reason.to_string(),
+        })
+    };
+    let Some(floor) = mint_fee.map(Asset::amount) else {
+        return invalid("a mint fee is required as the floor price");
+    };
+    match pricing {
+        PricingStrategy::DutchAuction {
+            start_price,
+            duration,
+            decay,
+            ..
+        } => {
+            if *start_price < floor {
+                return invalid("start price is less than the mint fee");
+            }
+            if *duration == 0 {
+                return invalid("duration must be non-zero");
+            }
+            if let Decay::Exponential { half_life: 0 } = decay {
+                return invalid("half life must be non-zero");
+            }
+        }
+        PricingStrategy::BondingCurve {
+            max_price,
+            exponent,
+        } => {
+            if *max_price < floor {
+                return invalid("max price is less than the mint fee");
+            }
+            if *exponent == 0 {
+                return invalid("exponent must be non-zero");
+            }
+        }
+        PricingStrategy::Oracle(oracle) => {
+            api.addr_validate(oracle.oracle.as_str())?;
+            if oracle.target_value.is_zero() {
+                return invalid("target value must be non-zero");
+            }
+            if oracle.max_staleness == 0 {
+                return invalid("max staleness must be non-zero");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Deducts the infusion mint fee from `funds`, returning the fee split & the fee paid.
+/// Each of `shares` is paid its share of the fee, with the rest of the fee split as usual.
+fn charge_mint_fee(
+    cfg: &Config,
+    infusion: &InfusionState,
+    sender: &Addr,
+    shares: &[(Addr, Decimal)],
+    mint_fee: Option<Coin>,
+    funds: &mut [Coin],
+) -> Result<(Vec<CosmosMsg>, Option<Coin>), ContractError> {
+    let Some(fee) = mint_fee else {
+        return Ok((vec![], None));
+    };
+    if *sender == infusion.owner {
+        // infusion owner omitted from fee payment
+        return Ok((vec![], None));
+    }
+    let Some(paid) = funds.iter_mut().find(|a| a.denom == fee.denom) else {
+        // reported by `assert_mint_fee_paid` once bundles are validated
+        return Ok((vec![], None));
+    };
+    // the amount sent bounds the fee charged, so price movements never overcharge the sender
+    if paid.amount < fee.amount {
+        return Err(ContractError::FeeNotAccepted {
+            have: paid.clone(),
+            want: fee,
+        });
+    }
+    paid.amount -= fee.amount;
+    let (paid_shares, retained) = split_fee_shares(&fee, shares)?;
+    let mut fee_msgs = form_feesplit_helper(
+        cfg.owner_fee,
+        cfg.contract_owner.to_string(),
+        infusion,
+        retained,
+    )?;
+    fee_msgs.extend(paid_shares.into_iter().map(|(addr, amount)| {
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: addr.to_string(),
+            amount: vec![amount],
+        })
+    }));
+    Ok((collapse_bank_sends(fee_msgs), Some(fee)))
+}
+
+/// Splits a mint fee into the shares paid off the top, & the remainder retained by the infusion.
+fn split_fee_shares(
+    fee: &Coin,
+    shares: &[(Addr, Decimal)],
+) -> StdResult<(Vec<(Addr, Coin)>, Coin)> {
+    let mut remaining = fee.amount;
+    let mut paid = vec![];
+    for (addr, share) in shares {
+        let amount = fee.amount * *share;
+        if amount.is_zero() {
+            continue;
+        }
+        remaining = remaining.checked_sub(amount)?;
+        paid.push((addr.clone(), coin(amount.u128(), fee.denom.clone())));
+    }
+    Ok((paid, coin(remaining.u128(), fee.denom.clone())))
+}
+
+/// Returns the source royalty recipients & shares of eligible collections with nfts being infused.
+/// Collections without a registered recipient fall back to their cw2981 royalty address.
+/// Royalties without a valid recipient are skipped, each reported with an event.
+fn source_royalties(
+    deps: Deps,
+    infusion: &InfusionState,
+    nfts: &[NFT],
+) -> StdResult<(Vec<(Addr, Decimal)>, Vec<Event>)> {
+    let mut shares = vec![];
+    let mut skipped = vec![];
+    for col in &infusion.collections {
+        let Some(share) = col.source_royalty else {
+            continue;
+        };
+        let Some(nft) = nfts.iter().find(|nft| nft.addr == col.addr) else {
+            continue;
+        };
+        let recipient = match ROYALTY_RECIPIENTS.may_load(deps.storage, &col.addr)? {
+            Some(recipient) => Ok(recipient),
+            None => deps
+                .querier
+                .query_wasm_smart::<Cw2981RoyaltiesInfoResponse>(
+                    col.addr.to_string(),
+                    &Cw2981QueryMsg::Extension {
+                        msg: Cw2981Query::RoyaltyInfo {
+                            token_id: nft.token_id.to_string(),
+                            sale_price: Uint128::new(100),
+                        },
+                    },
+                )
+                .and_then(|res| deps.api.addr_validate(&res.address)),
+        };
+        match recipient {
+            Ok(recipient) => shares.push((recipient, share)),
+            Err(err) => skipped.push(
+                RoyaltySkippedEvent {
+                    collection: &col.addr,
+                    reason: err.to_string(),
+                }
+                .into(),
+            ),
+        }
+    }
+    Ok((shares, skipped))
+}
+
+/// Validates the source royalty shares of eligible collections.
+/// Along with the referral share, these must not exceed the mint fee.
+fn validate_fee_shares(
+    collections: &[EligibleNFTCollection],
+    params: &InfusionParamState,
+) -> Result<(), ContractError> {
+    let mut total = params.referral_share.unwrap_or_default();
+    for col in collections {
+        let Some(share) = col.source_royalty else {
+            continue;
+        };
+        if share.is_zero() || share > Decimal::one() {
+            return Err(ContractError::InvalidSourceRoyalty {
+                collection: col.addr.to_string(),
+            });
+        }
+        total += share;
+    }
+    if total > Decimal::one() {
+        return Err(ContractError::FeeSharesExceeded { total });
+    }
+    Ok(())
+}
+
+/// Registers the source royalty recipient of a collection. Only its creator or contract admin may do so.
+fn execute_register_royalty_recipient(
+    deps: DepsMut,
+    info: MessageInfo,
+    collection: String,
+    recipient: Option<String>,
+) -> Result<Response, ContractError> {
+    let collection = deps.api.addr_validate(&collection)?;
+    let contract_info = deps.querier.query_wasm_contract_info(&collection)?;
+    if info.sender != contract_info.creator
+        && contract_info.admin.as_deref() != Some(info.sender.as_str())
+    {
+        return Err(ContractError::Unauthorized);
+    }
+    let recipient = recipient.map(|r| deps.api.addr_validate(&r)).transpose()?;
+    match &recipient {
+        Some(r) => ROYALTY_RECIPIENTS.save(deps.storage, &collection, r)?,
+        None => ROYALTY_RECIPIENTS.remove(deps.storage, &collection),
+    }
+    Ok(Response::new().add_event(RoyaltyRecipientEvent {
+        collection: &collection,
+        sender: &info.sender,
+        recipient: recipient.as_ref(),
+    }))
+}
+
+/// Returns the referral share of a mint fee.
+fn referral_reward(infusion: &InfusionState, fee: &Coin) -> Coin {
+    let share = infusion.infusion_params.referral_share.unwrap_or_default();
+    coin((fee.amount * share).u128(), fee.denom.clone())
+}
+
+/// Counts an infuse towards the referrers stats, along with the reward earned.
+fn record_referral(storage: &mut dyn Storage, referrer: &Addr, reward: Coin) -> StdResult<()> {
+    let mut stats = REFERRAL_STATS
+        .may_load(storage, referrer)?
+        .unwrap_or_default();
+    stats.referrals += 1;
+    if !reward.amount.is_zero() {
+        match stats.earned.iter_mut().find(|c| c.denom == reward.denom) {
+            Some(c) => c.amount += reward.amount,
+            None => stats.earned.push(reward),
+        }
+    }
+    REFERRAL_STATS.save(storage, referrer, &stats)
+}
+
+/// Errors if the infusion requires a mint fee from the sender that was not paid.
+fn assert_mint_fee_paid(
+    infusion: &InfusionState,
+    sender: &Addr,
+    mint_fee: Option<Coin>,
+    fee_paid: bool,
+) -> Result<(), ContractError> {
+    match mint_fee {
+        Some(want) if !fee_paid && *sender != infusion.owner => {
+            Err(ContractError::FeeNotAccepted {
+                have: coin(0, want.denom.clone()),
+                want,
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns any funds left unspent back to the sender.
+fn refund_msg(sender: &Addr, funds: Vec<Coin>) -> StdResult<Vec<CosmosMsg>> {
+    let amount: Vec<Coin> = funds.into_iter().filter(|c| !c.amount.is_zero()).collect();
+    if amount.is_empty() {
+        return Ok(vec![]);
+    }
+    route_transfers(vec![CosmosMsg::Bank(BankMsg::Send {
+        to_address: sender.to_string(),
+        amount,
+    })])
+}
+
+/// Replaces transfers of cw20 tokens, tracked as `cw20:` prefixed coins, with cw20 transfer messages.
+fn route_transfers(msgs: Vec<CosmosMsg>) -> StdResult<Vec<CosmosMsg>> {
+    let mut routed = vec![];
+    for msg in msgs {
+        let CosmosMsg::Bank(BankMsg::Send { to_address, amount }) = msg else {
+            routed.push(msg);
+            continue;
+        };
+        let (cw20s, native): (Vec<Coin>, Vec<Coin>) = amount
+            .into_iter()
+            .partition(|c| c.denom.starts_with(CW20_DENOM_PREFIX));
+        for c in cw20s {
+            if let Asset::Cw20 { address, amount } = Asset::from(c) {
+                routed.push(into_cosmos_msg(
+                    Cw20ExecuteMsg::Transfer {
+                        recipient: to_address.clone(),
+                        amount,
+                    },
+                    address,
+                    None,
+                )?);
+            }
+        }
+        if !native.is_empty() {
+            routed.push(CosmosMsg::Bank(BankMsg::Send {
+                to_address,
+                amount: native,
+            }));
+        }
+    }
+    Ok(routed)
+}
+
+/// Pairs each minted token id with the share of the bundle nfts used to mint it.
+fn split_ingredients<'a>(nfts: &'a [NFT], minted: &[u32]) -> Vec<(u32, &'a [NFT])> {
+    if minted.is_empty() {
+        return vec![];
+    }
+    let chunk_size = nfts.len().div_ceil(minted.len()).max(1);
+    let mut chunks = nfts.chunks(chunk_size);
+    minted
+        .iter()
+        .map(|token_id| (*token_id, chunks.next().unwrap_or_default()))
+        .collect()
+}
+
+/// Returns the amount of each coin consumed between two snapshots of the same funds.
+/// Coins are matched by denom, with any missing from `after` fully consumed.
+fn consumed_funds(before: &[Coin], after: &[Coin]) -> Vec<Coin> {
+    before
+        .iter()
+        .filter_map(|b| {
+            let left = after
+                .iter()
+                .find(|a| a.denom == b.denom)
+                .map_or(Uint128::zero(), |a| a.amount);
+            (b.amount > left).then(|| coin((b.amount - left).u128(), b.denom.clone()))
+        })
+        .collect()
+}
+
+/// Saves the provenance of each token minted from a bundle.
+#[allow(clippy::too_many_arguments)]
+fn record_provenance(
+    storage: &mut dyn Storage,
+    env: &Env,
+    infusion_id: u64,
+    burner: &Addr,
+    nfts: &[NFT],
+    minted: &[u32],
+    mut payment_substitutes: Vec<Coin>,
+    mut fee: Option<Coin>,
+) -> StdResult<()> {
+    for (token_id, burned) in split_ingredients(nfts, minted) {
+        provenance().save(
+            storage,
+            (infusion_id, token_id),
+            &Provenance {
+                burner: burner.clone(),
+                burned: burned.to_vec(),
+                payment_substitutes: std::mem::take(&mut payment_substitutes),
+                fee: fee.take(),
+                height: env.block.height,
             },
-            nft.addr,
-            None,
-        )?);
+        )?;
     }
+    Ok(())
+}
 
-    let prep_msgs = prepare_wasm_events(
-        env.clone(),
+/// Forms the msg disposing of an infused nft, as set by the infusions ingredient disposition.
+/// Nfts not burned are recorded along with their depositor.
+fn ingredient_disposition_msg(
+    storage: &mut dyn Storage,
+    env: &Env,
+    disposition: &IngredientDisposition,
+    infusion_id: u64,
+    depositor: &Addr,
+    nft: NFT,
+) -> Result<CosmosMsg, ContractError> {
+    let token_id = nft.token_id.to_string();
+    let holder = match disposition {
+        IngredientDisposition::Burn => {
+            return Ok(into_cosmos_msg(
+                cw721::Cw721ExecuteMsg::Burn { token_id },
+                nft.addr,
+                None,
+            )?);
+        }
+        IngredientDisposition::Vault { addr } => addr.clone(),
+        IngredientDisposition::Lock => env.contract.address.clone(),
+    };
+    ESCROWED_NFTS.save(
         storage,
-        &Addr::unchecked(
-            infusion
-                .infused_collection
-                .addr
-                .clone()
-                .expect("no-infusion-collection"),
-        ),
-        infusion.infused_collection.base_uri.clone(),
-        infusion_id,
-        paysub_msg_and_mc.1,
-        sender,
+        (&nft.addr, nft.token_id),
+        &EscrowedNft {
+            depositor: depositor.clone(),
+            infusion_id,
+            holder: holder.clone(),
+        },
     )?;
-    msgs.extend(prep_msgs.0);
-    msgs.extend(paysub_msg_and_mc.0);
-    // Return the final mint count which has been properly incremented for each token ID
-    Ok((msgs, prep_msgs.1))
+    Ok(into_cosmos_msg(
+        cw721::Cw721ExecuteMsg::TransferNft {
+            recipient: holder.to_string(),
+            token_id,
+        },
+        nft.addr,
+        None,
+    )?)
 }
 
+/// Forms the msgs to mint `mint_num` infused tokens to the recipient.
 fn prepare_wasm_events(
     env: Env,
     storage: &mut dyn Storage,
@@ -825,8 +2286,10 @@ fn prepare_wasm_events(
     infusion_id: u64,
     mint_num: u64,
     sender: &Addr,
-) -> Result<(Vec<CosmosMsg>, u64), ContractError> {
+    recipient: &Addr,
+) -> Result<(Vec<CosmosMsg>, u64, Vec<u32>), ContractError> {
     let mut msgs: Vec<CosmosMsg> = Vec::new();
+    let mut minted = Vec::new();
     let mut mc = MINT_COUNT.load(storage)?;
 
     for _ in 0..mint_num {
@@ -843,7 +2306,7 @@ fn prepare_wasm_events(
         // mint_msg
         let mint_msg: Cw721ExecuteMessage<Empty, Empty> = Cw721ExecuteMessage::Mint {
             token_id: token_id.token_id.to_string(),
-            owner: sender.to_string(),
+            owner: recipient.to_string(),
             token_uri: Some(format!(
                 "{}/{}{}",
                 infusion_base_uri.clone(),
@@ -854,20 +2317,55 @@ fn prepare_wasm_events(
         };
 
         msgs.push(into_cosmos_msg(mint_msg, infused_col_addr.clone(), None)?);
+        minted.push(token_id.token_id);
     }
-    Ok((msgs, mc))
+    Ok((msgs, mc, minted))
 }
 
 /// Checks all parameters of a bundle, returning the number of infused nfts to mint,
 /// along with a list of ComosMsgs (contract fees,etc) to append to the response.
+/// Any payment substitutes & fungible ingredients used are deducted from `sent`, the fungible
+/// ingredients burned are returned, and the senders leftover wavs burn count is stored.
 fn check_bundles(
     storage: &mut dyn Storage,
     cfg: &Config,
     infusion: &InfusionState,
     sender: &Addr,
     bundle: Vec<NFT>,
-    sent: &Vec<Coin>,
-) -> Result<(Vec<CosmosMsg>, u64), ContractError> {
+    sent: &mut Vec<Coin>,
+) -> Result<(Vec<CosmosMsg>, u64, Vec<Coin>), ContractError> {
+    let eval = evaluate_bundle(storage, cfg, infusion, sender, bundle, sent, &[])?;
+    for (col, overflow) in eval.wavs_tracked {
+        WAVS_TRACKED.save(storage, (sender, col), &overflow)?;
+    }
+    *sent = eval.funds;
+    Ok((eval.msgs, eval.mint_count, eval.fungibles_burned))
+}
+
+/// Outcome of evaluating a bundle against an infusion.
+struct BundleEval {
+    /// payment substitute fee split & fungible ingredient burn messages
+    msgs: Vec<CosmosMsg>,
+    mint_count: u64,
+    /// funds remaining after payment substitutes & fungible ingredients are consumed
+    funds: Vec<Coin>,
+    /// fungible ingredients burned from the funds
+    fungibles_burned: Vec<Coin>,
+    /// wavs burn count left over for each eligible collection
+    wavs_tracked: Vec<(String, u64)>,
+}
+
+/// Confirms a bundle satisfies an infusion without writing to storage.
+/// `wavs_credit` holds burn counts left by earlier bundles not yet stored, read before storage.
+fn evaluate_bundle(
+    storage: &dyn Storage,
+    cfg: &Config,
+    infusion: &InfusionState,
+    sender: &Addr,
+    bundle: Vec<NFT>,
+    sent: &[Coin],
+    wavs_credit: &[(String, u64)],
+) -> Result<BundleEval, ContractError> {
     let mut funds_sent = sent.to_vec();
     let iclen: usize = infusion.collections.len();
 
@@ -875,8 +2373,9 @@ fn check_bundles(
     let btype: i32 = bundle_type.strain();
     let wavs_enabled = infusion.infusion_params.wavs_enabled;
 
-
     let mut check_bundle_msgs = Vec::new();
+    let mut fungibles_burned = Vec::new();
+    let mut wavs_tracked = Vec::new();
 
     let mut total_bundle_map: Vec<AnyOfCount> = Vec::with_capacity(iclen);
     let mut fee_sub_map = Vec::new();
@@ -887,6 +2386,21 @@ fn check_bundles(
     let mut wavs_overflow = 0u64;
     let mut infused_mint_count = 0u64;
 
+    // fungible ingredients are required by AllOf bundles, & each satisfied mints once for AnyOf
+    for ingredient in &infusion.infusion_params.fungible_ingredients {
+        match burn_fungible_ingredient(ingredient, &mut funds_sent) {
+            Ok((burn, burned)) => {
+                check_bundle_msgs.push(burn);
+                fungibles_burned.push(burned);
+                if btype != 1 {
+                    infused_mint_count += 1;
+                }
+            }
+            Err(ContractError::FungibleIngredientNotProvided { .. }) if btype != 1 => {}
+            Err(err) => return Err(err),
+        }
+    }
+
     for eli in &infusion.collections {
         let elig = bundle
             .iter()
@@ -900,14 +2414,20 @@ fn check_bundles(
         if wavs_enabled {
             // count how many are burned from wavs record
             // determine how many times minimum requirement is satisfied, returning count + any overflow
-            wavs_burn_count += wavs_burn_count_helper(storage, eli.addr.clone(), sender)?;
+            wavs_burn_count += match wavs_credit
+                .iter()
+                .find(|(col, _)| *col == eli.addr.as_str())
+            {
+                Some((_, count)) => *count,
+                None => wavs_burn_count_helper(storage, eli.addr.clone(), sender)?,
+            };
             let wmch = wavs_mint_count_helper(wavs_burn_count, eli.min_req)?;
             wavs_satisfy_minimum = wmch.to_mint;
             wavs_overflow = wmch.remaining;
         }
 
         if elig_len != eli.min_req {
-            if let Some(ps) = &eli.payment_substitute {
+            if let Some(ps) = &eli.payment_substitute.as_ref().map(Asset::to_coin) {
                 let (len, remaining_funds) = check_fee_sub(btype, &eli.addr, ps, &funds_sent)?;
                 funds_sent = remaining_funds;
 
@@ -923,7 +2443,7 @@ fn check_bundles(
                         check_bundle_msgs.extend(form_feesplit_helper(
                             cfg.owner_fee,
                             cfg.contract_owner.to_string(),
-                            infusion.payment_recipient.to_string(),
+                            infusion,
                             ps.clone(),
                         )?);
                         fee_sub_map.push(eli.addr.to_string());
@@ -934,7 +2454,7 @@ fn check_bundles(
                             let msgmsgs = form_feesplit_helper(
                                 cfg.owner_fee,
                                 cfg.contract_owner.to_string(),
-                                infusion.payment_recipient.to_string(),
+                                infusion,
                                 ps.clone(),
                             )?;
                             // println!("msgmsgs: {:#?}", msgmsgs);
@@ -948,7 +2468,7 @@ fn check_bundles(
                             let fee_msgs = form_feesplit_helper(
                                 cfg.owner_fee,
                                 cfg.contract_owner.to_string(),
-                                infusion.payment_recipient.to_string(),
+                                infusion,
                                 ps.clone(),
                             )?;
                             check_bundle_msgs.extend(fee_msgs);
@@ -1069,7 +2589,7 @@ fn check_bundles(
                 wavs_overflow = total - used
             }
 
-            WAVS_TRACKED.save(storage, (sender, eli.addr.to_string()), &wavs_overflow)?;
+            wavs_tracked.push((eli.addr.to_string(), wavs_overflow));
         }
     }
     // prevent any ineligible nft from being included
@@ -1098,7 +2618,13 @@ fn check_bundles(
         infused_mint_count = 1;
     }
 
-    Ok((check_bundle_msgs, infused_mint_count))
+    Ok(BundleEval {
+        msgs: check_bundle_msgs,
+        mint_count: infused_mint_count,
+        funds: funds_sent,
+        fungibles_burned,
+        wavs_tracked,
+    })
 }
 
 /// Returns the # of nfts burned for an eligible collection, tracked by the wavs service.
@@ -1141,25 +2667,144 @@ fn wavs_mint_count_helper(
     }
 }
 
-/// Update the infused collection
-fn update_wavs_infusion_state(
+/// Records a batch signed by wavs operators, after verifying the signatures & nonce.
+/// The nonce must exceed the last one submitted by each signing operator.
+fn execute_wavs_signed_entry_point(
     deps: DepsMut,
-    info: MessageInfo,
+    env: Env,
     to_add: Vec<WavsBundle>,
+    nonce: u64,
+    signatures: Vec<WavsSignature>,
+) -> Result<Response, ContractError> {
+    let hash = wavs_signing_hash(&env.contract.address, &env.block.chain_id, nonce, &to_add)?;
+    let mut signers: Vec<&Binary> = vec![];
+    for sig in &signatures {
+        // repeated signatures of an operator are only counted once
+        if signers.contains(&&sig.public_key) {
+            continue;
+        }
+        let key_type = WAVS_OPERATORS
+            .may_load(deps.storage, sig.public_key.as_slice())?
+            .ok_or_else(|| ContractError::UnknownWavsOperator {
+                public_key: sig.public_key.to_base64(),
+            })?;
+        let last = WAVS_NONCES
+            .may_load(deps.storage, sig.public_key.as_slice())?
+            .unwrap_or_default();
+        if nonce <= last {
+            return Err(ContractError::StaleWavsNonce { nonce, last });
+        }
+        let valid = match key_type {
+            WavsKeyType::Secp256k1 => {
+                deps.api
+                    .secp256k1_verify(&hash, &sig.signature, &sig.public_key)
+            }
+            WavsKeyType::Ed25519 => deps
+                .api
+                .ed25519_verify(&hash, &sig.signature, &sig.public_key),
+        }
+        .map_err(StdError::from)?;
+        if !valid {
+            return Err(ContractError::InvalidWavsSignature {
+                public_key: sig.public_key.to_base64(),
+            });
+        }
+        signers.push(&sig.public_key);
+    }
+    let threshold = WAVS_THRESHOLD.may_load(deps.storage)?.unwrap_or(1);
+    if (signers.len() as u32) < threshold {
+        return Err(ContractError::WavsThresholdNotMet {
+            have: signers.len() as u32,
+            need: threshold,
+        });
+    }
+    for key in signers {
+        WAVS_NONCES.save(deps.storage, key.as_slice(), &nonce)?;
+    }
+    record_wavs_bundles(deps, to_add)
+}
+
+/// Updates the wavs operator keys & signature threshold. Only callable by the contract owner.
+fn update_wavs_operators(
+    deps: DepsMut,
+    info: MessageInfo,
+    to_add: Vec<WavsOperator>,
+    to_remove: Vec<Binary>,
+    threshold: Option<u32>,
 ) -> Result<Response, ContractError> {
-    let key = WAVS_ADMIN.load(deps.storage)?;
-    if key != info.sender.to_string() {
+    let cfg = CONFIG.load(deps.storage)?;
+    if cfg.contract_owner != info.sender {
         return Err(ContractError::Admin(AdminError::NotAdmin {}));
     }
+    for key in &to_remove {
+        WAVS_OPERATORS.remove(deps.storage, key.as_slice());
+    }
+    for op in &to_add {
+        validate_wavs_operator(op)?;
+        WAVS_OPERATORS.save(deps.storage, op.public_key.as_slice(), &op.key_type)?;
+    }
+    let threshold = match threshold {
+        Some(t) => t,
+        None => WAVS_THRESHOLD.may_load(deps.storage)?.unwrap_or(1),
+    };
+    let operators = WAVS_OPERATORS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .count() as u32;
+    if threshold == 0 || (operators > 0 && threshold > operators) {
+        return Err(ContractError::InvalidWavsThreshold {
+            threshold,
+            operators,
+        });
+    }
+    WAVS_THRESHOLD.save(deps.storage, &threshold)?;
+    Ok(Response::new().add_event(ConfigChangeEvent {
+        sender: &info.sender,
+        changes: vec![
+            ("wavs_operators", operators.to_string()),
+            ("wavs_threshold", threshold.to_string()),
+        ],
+    }))
+}
+
+/// Ensures an operator public key has the length of its key type.
+fn validate_wavs_operator(op: &WavsOperator) -> Result<(), ContractError> {
+    let valid_len = match op.key_type {
+        WavsKeyType::Secp256k1 => [33, 65].contains(&op.public_key.len()),
+        WavsKeyType::Ed25519 => op.public_key.len() == 32,
+    };
+    if !valid_len {
+        return Err(ContractError::InvalidWavsOperatorKey {
+            public_key: op.public_key.to_base64(),
+        });
+    }
+    Ok(())
+}
+
+/// Credits the nfts burnt by each burner in a wavs batch. Each burn may only be credited once.
+fn record_wavs_bundles(deps: DepsMut, to_add: Vec<WavsBundle>) -> Result<Response, ContractError> {
+    let mut events = vec![];
     // load infusion & assert there are only eligible collections
     for req in to_add {
         // expect all objects in array to be sorted by ccollection address
-        let mut count = req.infused_ids.len() as u64;
+        let credited = req.infused_ids.len() as u64;
+        let mut count = credited;
+        let burner = deps.api.addr_validate(&req.infuser)?;
+        let nft_addr = deps.api.addr_validate(&req.nft_addr)?;
+        for token_id in &req.infused_ids {
+            let key = (nft_addr.as_str(), token_id.as_str());
+            if WAVS_CREDITED_BURNS.has(deps.storage, key) {
+                return Err(ContractError::WavsBurnAlreadyCredited {
+                    collection: nft_addr.to_string(),
+                    token_id: token_id.clone(),
+                });
+            }
+            WAVS_CREDITED_BURNS.save(deps.storage, key, &burner)?;
+        }
 
         // save map of burned nfts for each collection by token burner
         WAVS_TRACKED.update(
             deps.storage,
-            (&deps.api.addr_validate(&req.infuser)?, req.nft_addr),
+            (&burner, nft_addr.to_string()),
             |state| match state {
                 Some(len) => {
                     count += len;
@@ -1168,8 +2813,14 @@ fn update_wavs_infusion_state(
                 None => Ok(count),
             },
         )?;
+        events.push(Event::from(WavsCreditEvent {
+            burner: burner.as_str(),
+            collection: nft_addr.as_str(),
+            credited,
+            total: count,
+        }));
     }
-    Ok(Response::new())
+    Ok(Response::new().add_events(events))
 }
 
 fn check_anyof_bundle_helper(
@@ -1201,7 +2852,7 @@ fn check_anyof_bundle_helper(
                     continue;
                 }
                 // check for accurate fee substitute amount
-                if let Some(fps) = &elig.nft.payment_substitute {
+                if let Some(fps) = &elig.nft.payment_substitute.as_ref().map(Asset::to_coin) {
                     // first check if any already has been sent
                     let sent = sent.iter().find(|coin| coin.denom == fps.denom);
                     if let Some(pay) = sent {
@@ -1237,6 +2888,42 @@ fn check_anyof_bundle_helper(
     Ok(mc)
 }
 
+/// Deducts a fungible ingredient from the funds sent, returning the burn message & amount burned.
+/// At least `min_req` & up to `max_req` is burned per bundle, leaving any excess to later bundles
+/// or the refund.
+fn burn_fungible_ingredient(
+    ingredient: &FungibleIngredient,
+    funds: &mut [Coin],
+) -> Result<(CosmosMsg, Coin), ContractError> {
+    let want = ingredient.min_req.to_coin();
+    let Some(have) = funds
+        .iter_mut()
+        .find(|c| c.denom == want.denom && c.amount >= want.amount)
+    else {
+        let have = funds
+            .iter()
+            .find(|c| c.denom == want.denom)
+            .cloned()
+            .unwrap_or(coin(0, want.denom.clone()));
+        return Err(ContractError::FungibleIngredientNotProvided { have, want });
+    };
+    let burned = coin(
+        have.amount
+            .min(ingredient.max_req.unwrap_or(want.amount))
+            .u128(),
+        want.denom,
+    );
+    have.amount -= burned.amount;
+
+    let msg = match Asset::from(burned.clone()) {
+        Asset::Native(c) => BankMsg::Burn { amount: vec![c] }.into(),
+        Asset::Cw20 { address, amount } => {
+            into_cosmos_msg(Cw20ExecuteMsg::Burn { amount }, address, None)?
+        }
+    };
+    Ok((msg, burned))
+}
+
 /// Checks if sent coins contains correct payment substitute for a given elig_addr.\
 /// Returns the number of nfts to mint for a given fee substitute.\
 /// Bundle types 2 & 3 return 0 if fee-sub not satisfied, bundle type 1 returns error.
@@ -1307,41 +2994,77 @@ fn update_config(
     if config.contract_owner != msg.sender {
         return Err(ContractError::Admin(AdminError::NotAdmin {}));
     }
+    let mut changes = vec![];
 
     if let Some(owner) = uc.contract_owner {
         config.contract_owner = deps.api.addr_validate(&owner)?;
+        changes.push(("contract_owner", owner));
     }
 
     if let Some(of) = uc.owner_fee {
         config.owner_fee = of;
+        changes.push(("owner_fee", of.to_string()));
     }
 
     if let Some(cf) = uc.min_creation_fee {
+        cf.validate(deps.api)?;
+        changes.push(("min_creation_fee", cf.to_string()));
         config.min_creation_fee = Some(cf);
     }
 
+    if let Some(acf) = uc.accepted_creation_fees {
+        if acf.iter().any(|f| f.amount().is_zero()) {
+            return Err(ContractError::InfusionFeeCannotbeZero);
+        }
+        for fee in &acf {
+            fee.validate(deps.api)?;
+        }
+        changes.push((
+            "accepted_creation_fees",
+            acf.iter()
+                .map(|f| f.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        ));
+        config.accepted_creation_fees = acf;
+    }
+
+    if let Some(escrow) = uc.escrow_fees {
+        changes.push(("escrow_fees", escrow.to_string()));
+        config.escrow_fees = escrow;
+    }
+
     if let Some(mif) = uc.min_infusion_fee {
+        mif.validate(deps.api)?;
+        changes.push(("min_infusion_fee", mif.to_string()));
         config.min_infusion_fee = Some(mif);
     }
 
     if let Some(mi) = uc.max_infusions {
         config.max_infusions = mi;
+        changes.push(("max_infusions", mi.to_string()));
     }
 
     if let Some(mpb) = uc.min_per_bundle {
         config.min_per_bundle = mpb;
+        changes.push(("min_per_bundle", mpb.to_string()));
     }
 
     if let Some(mb) = uc.max_bundles {
         config.max_bundles = mb;
+        changes.push(("max_bundles", mb.to_string()));
     }
 
     if let Some(ci) = uc.code_id {
         config.code_id = ci;
+        changes.push(("code_id", ci.to_string()));
     }
 
     CONFIG.save(deps.storage, &config)?;
-    Ok(Response::new())
+    Ok(Response::new().add_event(ConfigChangeEvent {
+        sender: &msg.sender,
+        changes,
+    }))
 }
 
 pub fn into_cosmos_msg<M: Serialize, T: Into<String>>(
@@ -1359,7 +3082,7 @@ pub fn into_cosmos_msg<M: Serialize, T: Into<String>>(
 }
 
 pub fn query_infusion_genetics(deps: Deps, id: u64) -> StdResult<Vec<CompatibleTraits>> {
-    match INFUSION
+    match infusions()
         .load(deps.storage, INFUSION_ID.load(deps.storage, id)?)?
         .infusion_params
         .params
@@ -1416,43 +3139,415 @@ pub fn query_retrieve_wavs_record(
     }
 }
 
+pub fn query_simulate_infuse(
+    deps: Deps,
+    now: Timestamp,
+    id: u64,
+    sender: Addr,
+    referrer: Option<Addr>,
+    bundle: Vec<Bundle>,
+    funds: Vec<Coin>,
+) -> StdResult<SimulateInfuseResponse> {
+    let cfg = CONFIG.load(deps.storage)?;
+    let key = INFUSION_ID.load(deps.storage, id)?;
+    let infusion = infusions().load(deps.storage, key)?;
+
+    let mut res = SimulateInfuseResponse {
+        mint_count: 0,
+        fee_msgs: vec![],
+        substitutes_consumed: vec![],
+        fungibles_burned: vec![],
+        wavs_credit_used: 0,
+        wavs_credit_left: 0,
+        error: None,
+    };
+    if infusion.infusion_params.wavs_enabled {
+        for eli in &infusion.collections {
+            res.wavs_credit_left += wavs_burn_count_helper(deps.storage, eli.addr.clone(), &sender)
+                .map_err(|e| StdError::generic_err(e.to_string()))?;
+        }
+    }
+    if let Err(err) = simulate_infuse(
+        deps,
+        now,
+        &cfg,
+        &infusion,
+        &sender,
+        referrer.as_ref(),
+        bundle,
+        funds,
+        &mut res,
+    ) {
+        res.error = Some(err.to_string());
+    }
+    Ok(res)
+}
+
+/// Mirrors `infuse_bundles` without writing to storage, recording the outcome in `res`.
+/// The wavs burn count left by each bundle is carried over to the next, as it would be once stored.
+#[allow(clippy::too_many_arguments)]
+fn simulate_infuse(
+    deps: Deps,
+    now: Timestamp,
+    cfg: &Config,
+    infusion: &InfusionState,
+    sender: &Addr,
+    referrer: Option<&Addr>,
+    bundle: Vec<Bundle>,
+    mut funds: Vec<Coin>,
+    res: &mut SimulateInfuseResponse,
+) -> Result<(), ContractError> {
+    if !infusion.enabled {
+        return Err(ContractError::InfusionIsEnded {});
+    }
+    if referrer == Some(sender) {
+        return Err(ContractError::InvalidReferrer {});
+    }
+    assert_bundle_ownership(deps.querier, sender, &bundle)?;
+    let consumed: Vec<NFT> = bundle.iter().flat_map(|b| b.nfts.clone()).collect();
+    let (mint_fee, shares, _) =
+        mint_fee_and_shares(deps, now, infusion, sender, referrer, &consumed)?;
+    let (fee_msgs, fee_paid) =
+        charge_mint_fee(cfg, infusion, sender, &shares, mint_fee.clone(), &mut funds)?;
+    res.fee_msgs.extend(fee_msgs);
+
+    let nfts: Vec<Vec<NFT>> = if bundle.is_empty() {
+        if !infusion.infusion_params.wavs_enabled {
+            return Err(ContractError::EmptyBundle);
+        }
+        vec![vec![]]
+    } else {
+        bundle.into_iter().map(|b| b.nfts).collect()
+    };
+    let mut wavs_credit = vec![];
+    for nfts in nfts {
+        let wavs_only = nfts.is_empty();
+        let eval = evaluate_bundle(
+            deps.storage,
+            cfg,
+            infusion,
+            sender,
+            nfts,
+            &funds,
+            &wavs_credit,
+        )?;
+        if wavs_only && eval.msgs.is_empty() {
+            return Err(ContractError::EmptyBundle);
+        }
+        // funds consumed past the fungible ingredients burned went to payment substitutes
+        let consumed = consumed_funds(&funds, &eval.funds);
+        res.substitutes_consumed
+            .extend(consumed_funds(&consumed, &eval.fungibles_burned));
+        res.fungibles_burned.extend(eval.fungibles_burned);
+        funds = eval.funds;
+        res.mint_count += eval.mint_count;
+        res.fee_msgs.extend(eval.msgs);
+        if infusion.infusion_params.wavs_enabled {
+            let left = eval
+                .wavs_tracked
+                .iter()
+                .map(|(_, count)| count)
+                .sum::<u64>();
+            res.wavs_credit_used += res.wavs_credit_left.saturating_sub(left);
+            res.wavs_credit_left = left;
+        }
+        wavs_credit = eval.wavs_tracked;
+    }
+    // fees are escrowed & cw20 transfers routed as they would be once infused
+    let fee_msgs = collapse_bank_sends(std::mem::take(&mut res.fee_msgs));
+    res.fee_msgs = route_transfers(split_escrowed_fees(cfg, fee_msgs).0)?;
+    assert_mint_fee_paid(infusion, sender, mint_fee, fee_paid.is_some())
+}
+
+pub fn query_infusion_stats(deps: Deps, id: u64) -> StdResult<InfusionStatsResponse> {
+    let infusion = query_infusion_by_id(deps, id)?;
+    let total_supply = infusion.infused_collection.num_tokens;
+    let remaining = match infusion.infused_collection.addr {
+        Some(addr) => MINTABLE_NUM_TOKENS
+            .may_load(deps.storage, addr)?
+            .unwrap_or(total_supply),
+        None => total_supply,
+    };
+    let stats = INFUSION_STATS
+        .may_load(deps.storage, id)?
+        .unwrap_or_default();
+    Ok(InfusionStatsResponse {
+        total_supply,
+        minted: total_supply.saturating_sub(remaining),
+        remaining,
+        infuse_count: stats.infuse_count,
+        unique_burners: stats.unique_burners,
+        burned: stats.burned,
+        fees: stats.fees,
+    })
+}
+
+/// returns the top burners of an infusion, most burns first.
+pub fn query_leaderboard(
+    deps: Deps,
+    id: u64,
+    limit: Option<u32>,
+) -> StdResult<Vec<LeaderboardEntry>> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    burner_stats()
+        .idx
+        .burns
+        .sub_prefix(id)
+        .range(deps.storage, None, None, Order::Descending)
+        .take(limit)
+        .map(|item| {
+            let ((_, addr), stats) = item?;
+            Ok(LeaderboardEntry {
+                addr,
+                burns: stats.burns,
+                mints: stats.mints,
+            })
+        })
+        .collect()
+}
+
+pub fn query_burner_history(
+    deps: Deps,
+    addr: Addr,
+    start_after: Option<(u64, u32)>,
+    limit: Option<u32>,
+) -> StdResult<Vec<ProvenanceEntry>> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start: Option<Bound<(u64, u32)>> = start_after.map(Bound::exclusive);
+    provenance()
+        .idx
+        .burner
+        .prefix(addr)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let ((id, token_id), provenance) = item?;
+            Ok(ProvenanceEntry {
+                id,
+                token_id,
+                provenance,
+            })
+        })
+        .collect()
+}
+
 pub fn query_config(deps: Deps) -> StdResult<Config> {
     let config: Config = CONFIG.load(deps.storage)?;
     Ok(config)
 }
 
 pub fn query_infusion(deps: Deps, addr: Addr, id: u64) -> StdResult<InfusionState> {
-    let infusion = INFUSION.load(deps.storage, (addr, id))?;
+    let infusion = infusions().load(deps.storage, (addr, id))?;
     Ok(infusion)
 }
 pub fn query_infusion_by_id(deps: Deps, id: u64) -> StdResult<InfusionState> {
     let infuser = INFUSION_ID.load(deps.storage, id)?;
-    let infusion = INFUSION.load(deps.storage, infuser)?;
+    let infusion = infusions().load(deps.storage, infuser)?;
     Ok(infusion)
 }
 
 pub fn query_infusions(deps: Deps, addr: Addr, index: u64) -> StdResult<InfusionsResponse> {
-    let mut infusions = vec![];
+    let list = infusions()
+        .prefix(addr)
+        .range(
+            deps.storage,
+            Some(Bound::inclusive(index)),
+            None,
+            Order::Ascending,
+        )
+        .take(MAX_QUERY_LIMIT as usize)
+        .map(|item| item.map(|(_, infusion)| infusion))
+        .collect::<StdResult<Vec<_>>>()?;
 
-    for i in index..=index + 30 {
-        let id = i;
-        // return the response for each
-        let state = INFUSION.load(deps.storage, (addr.clone(), id))?;
-        infusions.push(state);
+    Ok(InfusionsResponse { infusions: list })
+}
+
+pub fn query_infusions_by_owner(
+    deps: Deps,
+    owner: Addr,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    order: Option<QueryOrder>,
+) -> StdResult<Vec<InfusionSummary>> {
+    let order: Order = order.unwrap_or_default().into();
+    // owner index keys are (infusion_id, primary key) once prefixed by the owner
+    let start = start_after
+        .map(|id| INFUSION_ID.load(deps.storage, id).map(|pk| (id, pk)))
+        .transpose()?
+        .map(Bound::exclusive);
+    let (min, max) = match order {
+        Order::Ascending => (start, None),
+        Order::Descending => (None, start),
+    };
+    collect_infusions(
+        infusions()
+            .idx
+            .owner
+            .sub_prefix(owner)
+            .range(deps.storage, min, max, order),
+        limit,
+    )
+}
+
+pub fn query_infusions_by_collection(
+    deps: Deps,
+    addr: Addr,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    order: Option<QueryOrder>,
+) -> StdResult<Vec<InfusionSummary>> {
+    let order: Order = order.unwrap_or_default().into();
+    let start = start_after.map(Bound::exclusive);
+    let (min, max) = match order {
+        Order::Ascending => (start, None),
+        Order::Descending => (None, start),
+    };
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    infusions()
+        .prefix(addr)
+        .range(deps.storage, min, max, order)
+        .take(limit)
+        .map(|item| {
+            let (id, infusion) = item?;
+            Ok(InfusionSummary { id, infusion })
+        })
+        .collect()
+}
+
+pub fn query_all_infusions(
+    deps: Deps,
+    enabled: Option<bool>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    order: Option<QueryOrder>,
+) -> StdResult<Vec<InfusionSummary>> {
+    let order: Order = order.unwrap_or_default().into();
+    let (min, max) = infusion_key_bounds(deps, start_after, order)?;
+    match enabled {
+        Some(enabled) => collect_infusions(
+            infusions()
+                .idx
+                .enabled
+                .prefix(enabled as u8)
+                .range(deps.storage, min, max, order),
+            limit,
+        ),
+        None => collect_infusions(infusions().range(deps.storage, min, max, order), limit),
+    }
+}
+
+/// returns infusions a given nft collection is eligible for, ordered by infusion id.
+pub fn query_infusions_for_collection(
+    deps: Deps,
+    addr: &Addr,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<InfusionSummary>> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let mut ids = ELIGIBLE_COLLECTION
+        .may_load(deps.storage, &addr.to_string())?
+        .unwrap_or_default();
+    ids.sort_unstable();
+    ids.into_iter()
+        .filter(|id| match start_after {
+            Some(s) => *id > s,
+            None => true,
+        })
+        .take(limit)
+        .map(|id| {
+            Ok(InfusionSummary {
+                id,
+                infusion: query_infusion_by_id(deps, id)?,
+            })
+        })
+        .collect()
+}
+
+/// returns the infusions each of up to 10 nft collections is eligible for.
+pub fn query_infusions_for_collections(
+    deps: Deps,
+    addrs: Vec<Addr>,
+    limit: Option<u32>,
+) -> StdResult<Vec<CollectionInfusions>> {
+    // Limit 10 collections
+    if addrs.len() > 10usize {
+        return Err(StdError::generic_err(
+            "try to query less collections at once",
+        ));
+    }
+    addrs
+        .into_iter()
+        .map(|addr| {
+            Ok(CollectionInfusions {
+                infusions: query_infusions_for_collection(deps, &addr, None, limit)?,
+                addr,
+            })
+        })
+        .collect()
+}
+
+/// Re-saves every infusion, populating the owner & enabled indexes of infusions stored before they existed.
+fn reindex_infusions(storage: &mut dyn Storage) -> StdResult<()> {
+    let keys = infusions()
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for key in keys {
+        let infusion = infusions().load(storage, key.clone())?;
+        infusions().save(storage, key, &infusion)?;
     }
+    Ok(())
+}
+
+/// Range bounds over infusion keys, starting after the key of a given infusion id.
+fn infusion_key_bounds(
+    deps: Deps,
+    start_after: Option<u64>,
+    order: Order,
+) -> StdResult<(Option<Bound<(Addr, u64)>>, Option<Bound<(Addr, u64)>>)> {
+    let start = start_after
+        .map(|id| INFUSION_ID.load(deps.storage, id))
+        .transpose()?
+        .map(Bound::exclusive);
+    Ok(match order {
+        Order::Ascending => (start, None),
+        Order::Descending => (None, start),
+    })
+}
 
-    Ok(InfusionsResponse { infusions })
+fn collect_infusions(
+    iter: impl Iterator<Item = StdResult<((Addr, u64), InfusionState)>>,
+    limit: Option<u32>,
+) -> StdResult<Vec<InfusionSummary>> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    iter.take(limit)
+        .map(|item| {
+            let ((_, id), infusion) = item?;
+            Ok(InfusionSummary { id, infusion })
+        })
+        .collect()
 }
 
 pub fn query_if_is_in_bundle(deps: Deps, addr: Addr, id: u64) -> StdResult<bool> {
     let key = INFUSION_ID.load(deps.storage, id)?;
-    Ok(INFUSION
+    Ok(infusions()
         .load(deps.storage, key)?
         .collections
         .iter()
         .any(|a| a.addr == addr))
 }
 
+/// verifies all nfts in each bundle are of ownership of the current sender
+fn assert_bundle_ownership(
+    querier: QuerierWrapper,
+    sender: &Addr,
+    bundles: &[Bundle],
+) -> Result<(), ContractError> {
+    for bundle in bundles {
+        is_nft_owner(querier, sender.clone(), bundle.nfts.clone())?;
+    }
+    Ok(())
+}
+
 /// verifies all nfts defined in bundle are of ownership of the current sender
 pub fn is_nft_owner(
     querier: QuerierWrapper,
@@ -1569,36 +3664,188 @@ fn random_mintable_token_mapping(
 fn form_feesplit_helper(
     owner_fee: Decimal,
     owner: String,
-    payment_recipient: String,
+    infusion: &InfusionState,
     fee: Coin,
 ) -> Result<Vec<CosmosMsg>, ContractError> {
-    let mut msgs = vec![];
-    // split fees between contract owner and infusion owner
+    // split fees between contract owner and infusion fee recipients
     let dev_fee = fee
         .amount
         .checked_multiply_ratio(owner_fee.numerator(), owner_fee.denominator())?;
+    let mut remaining = fee.amount - dev_fee;
+    let mut payouts = vec![(owner, dev_fee)];
+
+    match infusion.fee_recipients.split_last() {
+        None => payouts.push((infusion.payment_recipient.to_string(), remaining)),
+        Some((last, rest)) => {
+            let total = remaining;
+            for recipient in rest {
+                let share = total.checked_multiply_ratio(
+                    recipient.weight.numerator(),
+                    recipient.weight.denominator(),
+                )?;
+                remaining -= share;
+                payouts.push((recipient.addr.to_string(), share));
+            }
+            // last recipient receives any remainder from rounding
+            payouts.push((last.addr.to_string(), remaining));
+        }
+    }
 
-    let dec = Decimal::one() - owner_fee;
-    let remaining_fee_amount = fee
-        .amount
-        .checked_multiply_ratio(dec.numerator(), dec.denominator())?;
+    let msgs = payouts
+        .into_iter()
+        .filter(|(_, amount)| !amount.is_zero())
+        .map(|(to_address, amount)| {
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address,
+                amount: vec![coin(amount.u128(), fee.denom.clone())],
+            })
+        })
+        .collect();
+    Ok(collapse_bank_sends(msgs))
+}
 
-    if dev_fee != Uint128::zero() {
-        let base_fee = CosmosMsg::Bank(BankMsg::Send {
-            to_address: owner,
-            amount: vec![coin(dev_fee.into(), fee.denom.clone())],
-        });
-        msgs.push(base_fee);
+/// Credits fee transfers to the escrow ledger if fee escrow is enabled, returning the messages left to send.
+fn settle_fees(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    cfg: &Config,
+    msgs: Vec<CosmosMsg>,
+) -> StdResult<Vec<CosmosMsg>> {
+    let (remaining, escrowed) = split_escrowed_fees(cfg, msgs);
+    for (to_address, amount) in escrowed {
+        let recipient = api.addr_validate(&to_address)?;
+        for c in amount {
+            FEE_BALANCES.update(storage, (&recipient, &c.denom), |bal| {
+                StdResult::Ok(bal.unwrap_or_default() + c.amount)
+            })?;
+            ESCROWED_FEES.update(storage, &c.denom, |bal| {
+                StdResult::Ok(bal.unwrap_or_default() + c.amount)
+            })?;
+        }
     }
+    Ok(remaining)
+}
 
-    // remaining fee to infusion owner
-    let fee_msg = CosmosMsg::Bank(BankMsg::Send {
-        to_address: payment_recipient.to_string(),
-        amount: vec![coin(remaining_fee_amount.into(), fee.denom.clone())],
-    });
+/// Separates fee transfers held in escrow, if fee escrow is enabled, from messages left to send.
+#[allow(clippy::type_complexity)]
+fn split_escrowed_fees(
+    cfg: &Config,
+    msgs: Vec<CosmosMsg>,
+) -> (Vec<CosmosMsg>, Vec<(String, Vec<Coin>)>) {
+    if !cfg.escrow_fees {
+        return (msgs, vec![]);
+    }
+    let mut remaining = vec![];
+    let mut escrowed = vec![];
+    for msg in msgs {
+        match msg {
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                escrowed.push((to_address, amount))
+            }
+            msg => remaining.push(msg),
+        }
+    }
+    (remaining, escrowed)
+}
 
-    msgs.push(fee_msg);
-    Ok(msgs)
+/// Sends the senders escrowed fee balances to them.
+fn execute_claim_fees(
+    deps: DepsMut,
+    info: MessageInfo,
+    denoms: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
+    let balances = FEE_BALANCES
+        .prefix(&info.sender)
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|item| {
+            item.as_ref()
+                .map(|(denom, _)| denoms.as_ref().is_none_or(|d| d.contains(denom)))
+                .unwrap_or(true)
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    if balances.is_empty() {
+        return Err(ContractError::NothingToClaim);
+    }
+
+    let mut amount = vec![];
+    for (denom, bal) in balances {
+        FEE_BALANCES.remove(deps.storage, (&info.sender, &denom));
+        ESCROWED_FEES.update(deps.storage, &denom, |total| {
+            StdResult::Ok(total.unwrap_or_default().saturating_sub(bal))
+        })?;
+        amount.push(coin(bal.u128(), denom));
+    }
+
+    let event = FeeClaimEvent {
+        recipient: &info.sender,
+        amount: &amount,
+    };
+    let res = Response::new().add_event(event);
+    Ok(
+        res.add_messages(route_transfers(vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount,
+        })])?),
+    )
+}
+
+/// Merges bank transfers into a single message per recipient & denom, keeping other messages in order.
+fn collapse_bank_sends(msgs: Vec<CosmosMsg>) -> Vec<CosmosMsg> {
+    let mut sends: Vec<(String, Coin)> = vec![];
+    let mut others = vec![];
+    for msg in msgs {
+        match msg {
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                for c in amount {
+                    match sends
+                        .iter_mut()
+                        .find(|(to, sent)| to == &to_address && sent.denom == c.denom)
+                    {
+                        Some((_, sent)) => sent.amount += c.amount,
+                        None => sends.push((to_address.clone(), c)),
+                    }
+                }
+            }
+            msg => others.push(msg),
+        }
+    }
+    others.extend(sends.into_iter().map(|(to_address, c)| {
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address,
+            amount: vec![c],
+        })
+    }));
+    others
+}
+
+/// Validates an infusions fee recipients, which must be unique with weights summing to 100%.
+fn validate_fee_recipients(
+    api: &dyn Api,
+    recipients: Vec<FeeRecipient>,
+) -> Result<Vec<FeeRecipient>, ContractError> {
+    let mut total = Decimal::zero();
+    let mut seen: Vec<Addr> = vec![];
+    for recipient in &recipients {
+        let addr = api.addr_validate(recipient.addr.as_str())?;
+        if seen.contains(&addr) {
+            return Err(ContractError::DuplicateFeeRecipient {
+                addr: addr.to_string(),
+            });
+        }
+        if recipient.weight.is_zero() {
+            return Err(ContractError::FeeRecipientWeightsError {
+                total: recipient.weight,
+            });
+        }
+        total = total
+            .checked_add(recipient.weight)
+            .map_err(StdError::from)?;
+        seen.push(addr);
+    }
+    if total != Decimal::one() {
+        return Err(ContractError::FeeRecipientWeightsError { total });
+    }
+    Ok(recipients)
 }
 
 // source: https://github.com/public-awesome/launchpad/blob/main/contracts/minters/token-merge-minter/src/contract.rs#L338
@@ -1635,9 +3882,13 @@ pub fn execute_shuffle(
     let randomized_token_ids = random_token_list(&env, info.sender.clone(), token_ids.clone())?;
     MINTABLE_TOKEN_VECTORS.save(deps.storage, inf_id, &randomized_token_ids)?;
 
-    Ok(res
-        .add_attribute("action", "shuffle")
-        .add_attribute("sender", info.sender))
+    // the value is the number of mintable tokens reordered
+    Ok(res.add_event(InfusionUpdateEvent {
+        infusion_id: inf_id,
+        sender: &info.sender,
+        field: "token_order",
+        value: randomized_token_ids.len().to_string(),
+    }))
 }
 
 // //  source: https://github.com/public-awesome/launchpad/blob/main/contracts/minters/vending-minter/src/contract.rs#L1371
@@ -1669,10 +3920,23 @@ pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> StdResult<Response>
     if prev_version.version < "0.6.0".to_string() {
         // v050_patch_upgrade(deps.storage).map_err(|e| StdError::generic_err(e.to_string()))?;
     }
+    reindex_infusions(deps.storage)?;
+
+    // sweep the contracts balance to the owner, leaving any fees held in escrow
     let to_address = CONFIG.load(deps.storage)?.contract_owner.to_string();
-    let amount = deps
+    let mut amount = vec![];
+    for mut bal in deps
         .querier
-        .query_all_balances(env.contract.address.to_string())?;
+        .query_all_balances(env.contract.address.to_string())?
+    {
+        let escrowed = ESCROWED_FEES
+            .may_load(deps.storage, &bal.denom)?
+            .unwrap_or_default();
+        bal.amount = bal.amount.saturating_sub(escrowed);
+        if !bal.amount.is_zero() {
+            amount.push(bal);
+        }
+    }
 
     // set new contract version
     let event = Event::new("migrate")
@@ -1681,9 +3945,11 @@ pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> StdResult<Response>
         .add_attribute("to_name", CONTRACT_NAME)
         .add_attribute("to_version", CONTRACT_VERSION);
 
-    Ok(res
-        .add_event(event)
-        .add_message(BankMsg::Send { to_address, amount }))
+    let mut res = res.add_event(event);
+    if !amount.is_empty() {
+        res = res.add_message(BankMsg::Send { to_address, amount });
+    }
+    Ok(res)
 }
 
 #[cfg(test)]
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use cosmwasm_std::{CheckedMultiplyRatioError, Coin, Instantiate2AddressError, StdError};
+use cosmwasm_std::{CheckedMultiplyRatioError, Coin, Decimal, Instantiate2AddressError, StdError};
 use cw_controllers::AdminError;
 use thiserror::Error;
 
@@ -18,6 +18,12 @@ pub enum ContractError {
     #[error("Fee payment not accepted. Ensure you are sending the correct amount.")]
     FeeNotAccepted { have: Coin, want: Coin },
 
+    #[error("Native funds cannot be sent with cw20 tokens")]
+    Cw20WithNativeFunds {},
+
+    #[error("Cw20 token {token} is not accepted")]
+    Cw20NotAccepted { token: String },
+
     #[error("{0}")]
     Instantiate2AddressError(#[from] Instantiate2AddressError),
 
@@ -27,6 +33,38 @@ pub enum ContractError {
     #[error("Cannot specify the same contract address more than once")]
     DuplicateCollectionInInfusion,
 
+    #[error("Fee recipient weights must each be non-zero and sum to 100%. Have: {total}")]
+    FeeRecipientWeightsError { total: Decimal },
+
+    #[error("Cannot specify the same fee recipient more than once: {addr}")]
+    DuplicateFeeRecipient { addr: String },
+
+    #[error("Invalid pricing strategy: {reason}")]
+    InvalidPricingStrategy { reason: String },
+
+    #[error("Invalid fee discount for collection {collection}")]
+    InvalidFeeDiscount { collection: String },
+
+    #[error("Referral share must be greater than 0 & at most 1")]
+    InvalidReferralShare {},
+
+    #[error("Referrer cannot be the sender")]
+    InvalidReferrer {},
+
+    #[error("Source royalty for collection {collection} must be greater than 0 & at most 1")]
+    InvalidSourceRoyalty { collection: String },
+
+    #[error("Source royalty & referral shares cannot exceed the mint fee. Have: {total}")]
+    FeeSharesExceeded { total: Decimal },
+
+    #[error("Fungible ingredient not provided. Have: {have}. Want: {want}")]
+    FungibleIngredientNotProvided { have: Coin, want: Coin },
+
+    #[error(
+        "Fungible ingredient {denom} must have a non-zero min_req, no greater than its max_req"
+    )]
+    InvalidFungibleIngredient { denom: String },
+
     #[error("Cannot currently set identical fee sub tokens for eligible collections: {token}")]
     DuplicateFeeSubToken { token: String },
 
@@ -50,6 +88,12 @@ pub enum ContractError {
     #[error("Bundle cannot be empty.")]
     EmptyBundle,
 
+    #[error("Pending bundle is full. Max: {max}")]
+    PendingBundleFull { max: u64 },
+
+    #[error("InfuseMany item {index} for infusion {id} failed: {err}")]
+    InfuseManyItemFailed { index: u64, id: u64, err: String },
+
     #[error("Bundle type AnyOf must only contain atleast 1 instance of any eligible collection")]
     AnyOfConfigError { err: AnyOfErr },
 
@@ -124,6 +168,42 @@ pub enum ContractError {
     #[error("InfusionIsEnded.")]
     InfusionIsEnded,
 
+    #[error("Redemption is not enabled for this infusion.")]
+    RedemptionDisabled,
+
+    #[error("Redemption requires the lock or vault ingredient disposition.")]
+    RedemptionRequiresEscrow,
+
+    #[error("No escrowed nfts to redeem for infused token: {token_id}")]
+    NothingToRedeem { token_id: u32 },
+
+    #[error("No escrowed fees to claim")]
+    NothingToClaim,
+
+    #[error("Public key {public_key} is not a wavs operator")]
+    UnknownWavsOperator { public_key: String },
+
+    #[error("Invalid wavs operator key {public_key}")]
+    InvalidWavsOperatorKey { public_key: String },
+
+    #[error("Invalid wavs signature from {public_key}")]
+    InvalidWavsSignature { public_key: String },
+
+    #[error("Not enough wavs operator signatures. Have: {have}. Need: {need}")]
+    WavsThresholdNotMet { have: u32, need: u32 },
+
+    #[error("Wavs threshold must be at least 1 & no more than the {operators} operators. Have: {threshold}")]
+    InvalidWavsThreshold { threshold: u32, operators: u32 },
+
+    #[error("Wavs submission nonce {nonce} must exceed the operator's last nonce: {last}")]
+    StaleWavsNonce { nonce: u64, last: u64 },
+
+    #[error("Burn of token {token_id} from collection {collection} already credited")]
+    WavsBurnAlreadyCredited {
+        collection: String,
+        token_id: String,
+    },
+
     #[error("InfusionDescriptionLengthError")]
     InfusionDescriptionLengthError,
 
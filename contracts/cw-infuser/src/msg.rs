@@ -1,10 +1,14 @@
 use crate::state::*;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Coin, Decimal};
+use cosmwasm_std::{Addr, Binary, Coin, CosmosMsg, Decimal, Order};
+use cw20::Cw20ReceiveMsg;
+use cw721::Cw721ReceiveMsg;
 use cw_infusions::{
+    assets::Asset,
     bundles::{Bundle, BundleType},
+    nfts::NFT,
     state::{EligibleNFTCollection, Infusion, InfusionState},
-    wavs::{WavsBundle, WavsRecordResponse},
+    wavs::{WavsBundle, WavsOperator, WavsOperatorsResponse, WavsRecordResponse, WavsSignature},
     CompatibleTraits,
 };
 
@@ -15,9 +19,13 @@ pub struct InstantiateMsg {
     /// Fee from each infusion payment, if required. Goes to contract owner during any infusion. Set to 0.0 to disable.
     pub owner_fee: Decimal,
     /// Minimum fee that is required for creating an infusion
-    pub min_creation_fee: Option<Coin>,
+    pub min_creation_fee: Option<Asset>,
+    /// Other coins accepted in place of `min_creation_fee`
+    pub accepted_creation_fees: Option<Vec<Asset>>,
+    /// Credit fees to a claimable balance instead of sending them during infusions. Defaults to false
+    pub escrow_fees: Option<bool>,
     /// Minimum fee that is required to be set when infusions occur
-    pub min_infusion_fee: Option<Coin>,
+    pub min_infusion_fee: Option<Asset>,
     /// Minimum tokens required for any infusions eligible collections
     pub min_per_bundle: Option<u64>,
     /// Maximim tokens required for any infusions eligible collections
@@ -28,8 +36,9 @@ pub struct InstantiateMsg {
     pub max_infusions: Option<u64>,
     /// Code-ID of the cw721-collection
     pub cw721_code_id: u64,
-    /// Optional key wavs operators make use of, if support is enabled
-    pub wavs_public_key: Option<String>,
+    /// Optional wavs operator key registered at instantiation. Submissions it signs are
+    /// verified like those of operators added later with `UpdateWavsOperators`.
+    pub wavs_public_key: Option<WavsOperator>,
 }
 
 #[cw_serde]
@@ -38,12 +47,54 @@ pub enum ExecuteMsg {
     CreateInfusion {
         infusions: Vec<Infusion>,
     },
-    WavsEntryPoint {
+    /// Submits a batch signed by wavs operators, relayable by any address.
+    /// The nonce must exceed the last one submitted by each signing operator.
+    WavsSignedEntryPoint {
         infusions: Vec<WavsBundle>,
+        nonce: u64,
+        signatures: Vec<WavsSignature>,
+    },
+    /// Adds or removes the wavs operator keys able to sign submissions, & the signatures required.
+    /// Only callable by the contract owner.
+    UpdateWavsOperators {
+        to_add: Vec<WavsOperator>,
+        to_remove: Vec<Binary>,
+        threshold: Option<u32>,
     },
     Infuse {
         id: u64,
         bundle: Vec<Bundle>,
+        /// Optional address to receive the infused tokens. Defaults to the sender.
+        /// Burn records & wavs credits are always attributed to the sender.
+        recipient: Option<String>,
+        /// Optional address rewarded with the infusions referral share of the mint fee.
+        referrer: Option<String>,
+    },
+    /// Infuse bundles into several infusions within a single message.
+    /// Each item is an infusion id and the bundles to infuse into it.
+    InfuseMany {
+        items: Vec<(u64, Vec<Bundle>)>,
+    },
+    /// cw721 receive hook. Nfts sent with `SendNft` are added to the senders
+    /// pending bundle for the infusion set in the `ReceiveNftMsg` payload.
+    ReceiveNft(Cw721ReceiveMsg),
+    /// cw20 receive hook. Tokens sent with `Send` pay the fees of the `ReceiveCw20Msg` payload.
+    Receive(Cw20ReceiveMsg),
+    /// Infuses the nfts in the senders pending bundle, as a single bundle.
+    CompleteBundle {
+        id: u64,
+        recipient: Option<String>,
+        /// Optional address rewarded with the infusions referral share of the mint fee.
+        referrer: Option<String>,
+    },
+    /// Returns the nfts in the senders pending bundle.
+    WithdrawPendingBundle {
+        id: u64,
+    },
+    /// Burns an infused token to redeem the escrowed nfts used to create it.
+    Uninfuse {
+        id: u64,
+        token_id: u32,
     },
     EndInfusion {
         id: u64,
@@ -63,7 +114,17 @@ pub enum ExecuteMsg {
     },
     UpdateInfusionMintFee {
         id: u64,
-        mint_fee: Option<Coin>,
+        mint_fee: Option<Asset>,
+    },
+    /// Withdraws the senders escrowed fee balances, optionally only for the given denoms.
+    ClaimFees {
+        denoms: Option<Vec<String>>,
+    },
+    /// Registers the recipient of source royalties for a collection. Callable by the collections
+    /// creator or contract admin. Removes the registered recipient if none is provided.
+    RegisterRoyaltyRecipient {
+        collection: String,
+        recipient: Option<String>,
     },
 
     Shuffle {
@@ -71,6 +132,29 @@ pub enum ExecuteMsg {
     },
 }
 
+/// Payload expected by the cw721 receive hook
+#[cw_serde]
+pub enum ReceiveNftMsg {
+    /// Adds the nft to the senders pending bundle for an infusion
+    AddToBundle { id: u64 },
+}
+
+/// Payload expected by the cw20 receive hook
+#[cw_serde]
+pub enum ReceiveCw20Msg {
+    /// Infuses bundles, paying the mint fee & payment substitutes with the tokens sent
+    Infuse {
+        id: u64,
+        bundle: Vec<Bundle>,
+        recipient: Option<String>,
+        referrer: Option<String>,
+    },
+    /// Redeems an infused token, paying the redemption fee with the tokens sent
+    Uninfuse { id: u64, token_id: u32 },
+    /// Creates infusions, paying the creation fee with the tokens sent
+    CreateInfusion { infusions: Vec<Infusion> },
+}
+
 #[cw_serde]
 #[derive(QueryResponses, cw_orch::QueryFns)]
 pub enum QueryMsg {
@@ -82,14 +166,132 @@ pub enum QueryMsg {
     /// returns an infusion for a given infusion id.
     #[returns(InfusionState)]
     InfusionById { id: u64 },
-    /// returns all infusions owned by a given address
-    /// defaults to 30 entries from a given index point of the infusion map.
-    /// TODO: optimize pagination
+    /// returns infusions minting a given infused collection, from infusion id `index` onwards.
+    /// defaults to 30 entries.
     #[returns(InfusionsResponse)]
     Infusions { addr: Addr, index: u64 },
+    /// returns infusions owned by a given address, ordered by infusion id.
+    /// defaults to 10 entries, max 30.
+    #[returns(Vec<InfusionSummary>)]
+    InfusionsByOwner {
+        owner: Addr,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        order: Option<QueryOrder>,
+    },
+    /// returns infusions minting a given infused collection, ordered by infusion id.
+    #[returns(Vec<InfusionSummary>)]
+    InfusionsByCollection {
+        addr: Addr,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        order: Option<QueryOrder>,
+    },
+    /// returns infusions a given nft collection is eligible for, ordered by infusion id.
+    /// defaults to 10 entries, max 30.
+    #[returns(Vec<InfusionSummary>)]
+    InfusionsForCollection {
+        addr: Addr,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// returns the first `limit` infusions each of the given nft collections is eligible for.
+    /// At most 10 collections may be queried at once.
+    #[returns(Vec<CollectionInfusions>)]
+    InfusionsForCollections {
+        addrs: Vec<Addr>,
+        limit: Option<u32>,
+    },
+    /// returns all infusions, optionally only those enabled or ended.
+    #[returns(Vec<InfusionSummary>)]
+    AllInfusions {
+        enabled: Option<bool>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        order: Option<QueryOrder>,
+    },
     #[returns(Vec<CompatibleTraits>)]
     InfusionGenetics { id: u64 },
 
+    /// returns the escrow record of an nft vaulted or locked during an infusion, if any.
+    #[returns(Option<EscrowedNft>)]
+    EscrowedNft { addr: Addr, token_id: u64 },
+
+    /// returns the escrowed nfts used to mint an infused token.
+    #[returns(Vec<NFT>)]
+    InfusedIngredients { id: u64, token_id: u32 },
+
+    /// dry run of infusing `bundle` into an infusion as `sender`, with `funds` attached.
+    #[returns(SimulateInfuseResponse)]
+    SimulateInfuse {
+        id: u64,
+        sender: Addr,
+        /// optional referrer paid the infusions referral share
+        referrer: Option<Addr>,
+        bundle: Vec<Bundle>,
+        funds: Vec<Coin>,
+    },
+
+    /// returns the mint fee currently required by an infusion, following its pricing strategy.
+    #[returns(Option<Asset>)]
+    CurrentPrice { id: u64 },
+
+    /// returns supply & running stats of an infusion.
+    #[returns(InfusionStatsResponse)]
+    InfusionStats { id: u64 },
+
+    /// returns the top burners of an infusion, most burns first. defaults to 10 entries, max 30.
+    #[returns(Vec<LeaderboardEntry>)]
+    Leaderboard { id: u64, limit: Option<u32> },
+
+    /// returns burn & mint counts of an address for an infusion.
+    #[returns(BurnerStats)]
+    BurnerStats { id: u64, addr: Addr },
+
+    /// returns the provenance record of an infused token, if any.
+    #[returns(Option<Provenance>)]
+    Provenance { id: u64, token_id: u32 },
+
+    /// returns provenance records of tokens infused by a given burner.
+    /// `start_after` is an (infusion_id, token_id) pair. defaults to 10 entries, max 30.
+    #[returns(Vec<ProvenanceEntry>)]
+    BurnerHistory {
+        addr: Addr,
+        start_after: Option<(u64, u32)>,
+        limit: Option<u32>,
+    },
+
+    /// returns the escrowed fees claimable by an address.
+    #[returns(Vec<Asset>)]
+    FeeBalance { addr: Addr },
+
+    /// returns the total fees held in escrow for all recipients.
+    #[returns(Vec<Asset>)]
+    EscrowedFees {},
+
+    /// returns the source royalty recipient registered for a collection.
+    #[returns(Option<Addr>)]
+    RoyaltyRecipient { collection: Addr },
+
+    /// returns the infuses referred by an address & the referral rewards earned.
+    #[returns(ReferralStatsResponse)]
+    ReferralStats { addr: Addr },
+
+    /// returns the wavs operator keys & signature threshold for signed submissions.
+    #[returns(WavsOperatorsResponse)]
+    WavsOperators {},
+
+    /// returns the last nonce submitted by a wavs operator, or 0 if none.
+    #[returns(u64)]
+    WavsOperatorNonce { public_key: Binary },
+
+    /// returns whether the burn of a token has already been credited by wavs.
+    #[returns(bool)]
+    WavsBurnCredited {
+        collection: String,
+        token_id: String,
+    },
+
     #[returns(Vec<WavsRecordResponse>)]
     WavsRecord {
         nfts: Vec<String>,
@@ -117,5 +319,84 @@ pub struct InfusionsResponse {
     pub infusions: Vec<InfusionState>,
 }
 
+#[cw_serde]
+pub struct InfusionSummary {
+    pub id: u64,
+    pub infusion: InfusionState,
+}
+
+#[cw_serde]
+pub struct CollectionInfusions {
+    pub addr: Addr,
+    pub infusions: Vec<InfusionSummary>,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub enum QueryOrder {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl From<QueryOrder> for Order {
+    fn from(order: QueryOrder) -> Self {
+        match order {
+            QueryOrder::Ascending => Order::Ascending,
+            QueryOrder::Descending => Order::Descending,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct SimulateInfuseResponse {
+    /// infused tokens that would be minted
+    pub mint_count: u64,
+    /// mint fee & payment substitute transfers, & fungible ingredient burns.
+    /// Fees held in escrow while fee escrow is enabled are not sent
+    pub fee_msgs: Vec<CosmosMsg>,
+    /// payment substitutes consumed from funds
+    pub substitutes_consumed: Vec<Coin>,
+    /// fungible ingredients burned from funds
+    pub fungibles_burned: Vec<Coin>,
+    pub wavs_credit_used: u64,
+    pub wavs_credit_left: u64,
+    /// first validation error, if the infusion would fail
+    pub error: Option<String>,
+}
+
+#[cw_serde]
+pub struct ReferralStatsResponse {
+    pub referrals: u64,
+    pub earned: Vec<Asset>,
+}
+
+#[cw_serde]
+pub struct InfusionStatsResponse {
+    pub total_supply: u32,
+    pub minted: u32,
+    pub remaining: u32,
+    pub infuse_count: u64,
+    pub unique_burners: u64,
+    /// nfts consumed per eligible collection
+    pub burned: Vec<CollectionBurnCount>,
+    /// mint fees retained after royalty & referral shares, per denom
+    pub fees: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct LeaderboardEntry {
+    pub addr: Addr,
+    pub burns: u64,
+    pub mints: u64,
+}
+
+#[cw_serde]
+pub struct ProvenanceEntry {
+    pub id: u64,
+    pub token_id: u32,
+    pub provenance: Provenance,
+}
+
 #[cw_serde]
 pub struct MigrateMsg {}
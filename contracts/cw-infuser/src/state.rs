@@ -1,13 +1,24 @@
-use cosmwasm_std::{Addr, Coin, Decimal, HexBinary};
-use cw_infusions::state::InfusionState;
-use cw_storage_plus::{Item, Map};
+use cosmwasm_std::{Addr, Coin, Decimal, HexBinary, Uint128};
+use cw_infusions::{assets::Asset, nfts::NFT, state::InfusionState, wavs::WavsKeyType};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 
 /// Global contract config
 pub const CONFIG: Item<Config> = Item::new("cfg");
 /// Map of the infusion id with the infused collection addr
 pub const INFUSION_ID: Map<u64, (Addr, u64)> = Map::new("infusion_id");
-/// infusions saved to map with key of (infused_collection_addr, infusion_id )
-pub const INFUSION: Map<(Addr, u64), InfusionState> = Map::new("infusion");
+/// infusions saved to map with key of (infused_collection_addr, infusion_id ), indexed by owner & enabled status.
+/// Infusions of an infused collection are queried by the key prefix.
+pub fn infusions<'a>() -> IndexedMap<'a, (Addr, u64), InfusionState, InfusionIndexes<'a>> {
+    let indexes = InfusionIndexes {
+        owner: MultiIndex::new(
+            |pk, i| (i.owner.clone(), pk_infusion_id(pk)),
+            "infusion",
+            "infusion__owner",
+        ),
+        enabled: MultiIndex::new(|_pk, i| i.enabled as u8, "infusion", "infusion__enabled"),
+    };
+    IndexedMap::new("infusion", indexes)
+}
 // map of infusion id's a given nft collection is eligible for. Used by WAVS service to filter trigger events.
 pub const ELIGIBLE_COLLECTION: Map<&String, Vec<u64>> = Map::new("eligible-collections");
 // map of index position and token id
@@ -16,10 +27,50 @@ pub const MINT_COUNT: Item<u64> = Item::new("mtc");
 pub const MINTABLE_TOKEN_VECTORS: Map<u64, Vec<u32>> = Map::new("mt_vectors");
 /// Number of mintable tokens for a given infused NFT collection
 pub const MINTABLE_NUM_TOKENS: Map<String, u32> = Map::new("mnt");
+/// nfts sent to the infuser with `SendNft`, pending a complete bundle. key: (depositor, infusion_id)
+pub const PENDING_BUNDLES: Map<(&Addr, u64), Vec<NFT>> = Map::new("pb");
+/// nfts vaulted or locked by an infusion instead of being burned. key: (collection, token_id)
+pub const ESCROWED_NFTS: Map<(&Addr, u64), EscrowedNft> = Map::new("escrowed");
+/// escrowed nfts used to mint an infused token. key: (infusion_id, infused token_id)
+pub const INFUSED_INGREDIENTS: Map<(u64, u32), Vec<NFT>> = Map::new("ingredients");
+/// provenance of each infused token, indexed by burner. key: (infusion_id, infused token_id)
+pub fn provenance<'a>() -> IndexedMap<'a, (u64, u32), Provenance, ProvenanceIndexes<'a>> {
+    let indexes = ProvenanceIndexes {
+        burner: MultiIndex::new(|_pk, p| p.burner.clone(), "prov", "prov__burner"),
+    };
+    IndexedMap::new("prov", indexes)
+}
+/// running stats of each infusion, updated on every infuse
+pub const INFUSION_STATS: Map<u64, InfusionStats> = Map::new("istats");
+/// burn & mint counts of each address infusing into an infusion, ordered by burns. key: (infusion_id, burner)
+pub fn burner_stats<'a>() -> IndexedMap<'a, (u64, Addr), BurnerStats, BurnerStatsIndexes<'a>> {
+    let indexes = BurnerStatsIndexes {
+        burns: MultiIndex::new(
+            |_pk, s| (s.infusion_id, s.burns),
+            "burners",
+            "burners__burns",
+        ),
+    };
+    IndexedMap::new("burners", indexes)
+}
+/// source royalty recipients registered by collection creators. key: collection
+pub const ROYALTY_RECIPIENTS: Map<&Addr, Addr> = Map::new("royalty_rcpt");
+/// infuses referred by each address & the referral rewards earned
+pub const REFERRAL_STATS: Map<&Addr, ReferralStats> = Map::new("referrals");
+/// fees credited to recipients while fee escrow is enabled, claimable with `ClaimFees`. key: (recipient, denom)
+pub const FEE_BALANCES: Map<(&Addr, &str), Uint128> = Map::new("fee_bal");
+/// total fees held in escrow for each denom.
+pub const ESCROWED_FEES: Map<&str, Uint128> = Map::new("fee_esc");
+/// burns credited by wavs, with the burner they were credited to. key: (collection addr, token_id)
+pub const WAVS_CREDITED_BURNS: Map<(&str, &str), Addr> = Map::new("wavs_burns");
 /// map to count tokens burnt for (token_burner, collection addr) as key.
 pub const WAVS_TRACKED: Map<(&Addr, String), u64> = Map::new("wt");
-
-pub const WAVS_ADMIN: Item<String> = Item::new("wavs_admin");
+/// key types of WAVS operator public keys able to sign submissions. key: public key
+pub const WAVS_OPERATORS: Map<&[u8], WavsKeyType> = Map::new("wavs_ops");
+/// signatures from distinct operators required per signed submission. Defaults to 1.
+pub const WAVS_THRESHOLD: Item<u32> = Item::new("wavs_threshold");
+/// last signed submission nonce of each wavs operator. key: public key
+pub const WAVS_NONCES: Map<&[u8], u64> = Map::new("wavs_op_nonces");
 /// Global con
 pub const COUNT: Item<i32> = Item::new("count");
 /// map of infusion
@@ -35,9 +86,15 @@ pub struct Config {
     /// % Fee from any infusion fee set to go to contract owner. 10 == 10% , 71 == 71%
     pub owner_fee: Decimal,
     /// Minimum fee that is required for creating an infusion.
-    pub min_creation_fee: Option<Coin>,
+    pub min_creation_fee: Option<Asset>,
+    /// Other coins accepted in place of `min_creation_fee`.
+    #[serde(default)]
+    pub accepted_creation_fees: Vec<Asset>,
+    /// If set, fees are credited to recipients & withdrawn with `ClaimFees`, rather than sent during infusions.
+    #[serde(default)]
+    pub escrow_fees: bool,
     /// Minimum fee that is required to be set when new infusions are being created
-    pub min_infusion_fee: Option<Coin>,
+    pub min_infusion_fee: Option<Asset>,
     /// Maximum unique infusion that can be created at once. Defaults to 2
     pub max_infusions: u64,
     /// Contract global param enforcing minimum nfts each collection in an infusion must require to burn. hard coded to 1.
@@ -53,11 +110,14 @@ pub struct Config {
 }
 
 #[cosmwasm_schema::cw_serde]
+#[derive(Default)]
 pub struct UpdatingConfig {
     pub contract_owner: Option<String>,
     pub owner_fee: Option<Decimal>,
-    pub min_creation_fee: Option<Coin>,
-    pub min_infusion_fee: Option<Coin>,
+    pub min_creation_fee: Option<Asset>,
+    pub accepted_creation_fees: Option<Vec<Asset>>,
+    pub escrow_fees: Option<bool>,
+    pub min_infusion_fee: Option<Asset>,
     pub max_infusions: Option<u64>,
     pub min_per_bundle: Option<u64>,
     pub max_bundles: Option<u64>,
@@ -69,3 +129,105 @@ pub struct TokenPositionMapping {
     pub position: u32,
     pub token_id: u32,
 }
+
+#[cosmwasm_schema::cw_serde]
+pub struct EscrowedNft {
+    /// Address that infused the nft
+    pub depositor: Addr,
+    pub infusion_id: u64,
+    /// Address holding the nft. Either a vault, or this contract when locked.
+    pub holder: Addr,
+}
+
+#[cosmwasm_schema::cw_serde]
+#[derive(Default)]
+pub struct ReferralStats {
+    pub referrals: u64,
+    /// referral rewards earned, per denom
+    pub earned: Vec<Coin>,
+}
+
+#[cosmwasm_schema::cw_serde]
+#[derive(Default)]
+pub struct InfusionStats {
+    /// number of successful infuse calls
+    pub infuse_count: u64,
+    pub unique_burners: u64,
+    /// nfts consumed per eligible collection
+    pub burned: Vec<CollectionBurnCount>,
+    /// mint fees retained after royalty & referral shares, & payment substitutes used, per denom
+    pub fees: Vec<Coin>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct CollectionBurnCount {
+    pub addr: Addr,
+    pub count: u64,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct BurnerStats {
+    pub infusion_id: u64,
+    /// nfts consumed by the burner
+    pub burns: u64,
+    /// infused tokens minted by the burner
+    pub mints: u64,
+}
+
+pub struct BurnerStatsIndexes<'a> {
+    pub burns: MultiIndex<'a, (u64, u64), BurnerStats, (u64, Addr)>,
+}
+
+impl<'a> IndexList<BurnerStats> for BurnerStatsIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<BurnerStats>> + '_> {
+        let v: Vec<&dyn Index<BurnerStats>> = vec![&self.burns];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Record of how an infused token came to be minted.
+/// When a bundle mints several tokens, its payment substitutes & the mint fee are recorded on the first token only.
+#[cosmwasm_schema::cw_serde]
+pub struct Provenance {
+    /// Address that infused the bundle
+    pub burner: Addr,
+    /// nfts consumed (burnt, vaulted or locked) to mint the infused token
+    pub burned: Vec<NFT>,
+    /// payment substitutes consumed in place of nfts
+    pub payment_substitutes: Vec<Coin>,
+    /// mint fee paid
+    pub fee: Option<Coin>,
+    pub height: u64,
+}
+
+/// Infusion id of a raw infusions primary key, which ends with the big-endian id.
+fn pk_infusion_id(pk: &[u8]) -> u64 {
+    let mut id = [0u8; 8];
+    id.copy_from_slice(&pk[pk.len() - 8..]);
+    u64::from_be_bytes(id)
+}
+
+pub struct InfusionIndexes<'a> {
+    /// (owner, infusion_id), so infusions of an owner are ordered by id
+    pub owner: MultiIndex<'a, (Addr, u64), InfusionState, (Addr, u64)>,
+    /// 1 if enabled, 0 otherwise
+    pub enabled: MultiIndex<'a, u8, InfusionState, (Addr, u64)>,
+}
+
+impl<'a> IndexList<InfusionState> for InfusionIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<InfusionState>> + '_> {
+        let v: Vec<&dyn Index<InfusionState>> = vec![&self.owner, &self.enabled];
+        Box::new(v.into_iter())
+    }
+}
+
+pub struct ProvenanceIndexes<'a> {
+    pub burner: MultiIndex<'a, Addr, Provenance, (u64, u32)>,
+}
+
+impl<'a> IndexList<Provenance> for ProvenanceIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Provenance>> + '_> {
+        let v: Vec<&dyn Index<Provenance>> = vec![&self.burner];
+        Box::new(v.into_iter())
+    }
+}
@@ -18,6 +18,7 @@ pub fn v050_patch_upgrade(storage: &mut dyn Storage) -> Result<(), ContractError
             key,
             InfusionState {
                 payment_recipient: Addr::unchecked(v040.payment_recipient),
+                fee_recipients: vec![],
                 enabled: v040.enabled,
                 owner: v040.owner,
                 collections: v040
@@ -28,6 +29,7 @@ pub fn v050_patch_upgrade(storage: &mut dyn Storage) -> Result<(), ContractError
                         min_req: col.min_req,
                         max_req: col.max_req,
                         payment_substitute: col.payment_substitute.clone(),
+                        source_royalty: None,
                     })
                     .collect(),
                 infused_collection: InfusedCollection {
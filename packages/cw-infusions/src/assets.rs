@@ -0,0 +1,71 @@
+use std::fmt;
+
+use cosmwasm_std::{coin, Addr, Api, Coin, StdError, StdResult, Uint128};
+
+/// Prefix of the denom used to track cw20 tokens alongside native funds.
+pub const CW20_DENOM_PREFIX: &str = "cw20:";
+
+/// A native or cw20 token amount. Untagged, so native amounts keep the `Coin` json format.
+#[cosmwasm_schema::cw_serde]
+#[serde(untagged)]
+pub enum Asset {
+    Native(Coin),
+    Cw20 { address: Addr, amount: Uint128 },
+}
+
+impl Asset {
+    pub fn amount(&self) -> Uint128 {
+        match self {
+            Asset::Native(c) => c.amount,
+            Asset::Cw20 { amount, .. } => *amount,
+        }
+    }
+
+    /// Denom used when tracking this asset in funds, `cw20:<address>` for cw20 tokens.
+    pub fn denom(&self) -> String {
+        match self {
+            Asset::Native(c) => c.denom.clone(),
+            Asset::Cw20 { address, .. } => format!("{CW20_DENOM_PREFIX}{address}"),
+        }
+    }
+
+    pub fn to_coin(&self) -> Coin {
+        coin(self.amount().u128(), self.denom())
+    }
+
+    /// Validates the token address of cw20 assets, & that native denoms cannot pass as cw20 tokens.
+    pub fn validate(&self, api: &dyn Api) -> StdResult<()> {
+        match self {
+            Asset::Cw20 { address, .. } => {
+                api.addr_validate(address.as_str())?;
+            }
+            Asset::Native(c) if c.denom.starts_with(CW20_DENOM_PREFIX) => {
+                return Err(StdError::generic_err(format!(
+                    "Native denom {} must not use the {CW20_DENOM_PREFIX} prefix",
+                    c.denom
+                )));
+            }
+            Asset::Native(_) => {}
+        }
+        Ok(())
+    }
+}
+
+/// Parses coins tracked with a `cw20:` prefixed denom back into cw20 assets.
+impl From<Coin> for Asset {
+    fn from(c: Coin) -> Self {
+        match c.denom.strip_prefix(CW20_DENOM_PREFIX) {
+            Some(address) => Asset::Cw20 {
+                address: Addr::unchecked(address),
+                amount: c.amount,
+            },
+            None => Asset::Native(c),
+        }
+    }
+}
+
+impl fmt::Display for Asset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_coin())
+    }
+}
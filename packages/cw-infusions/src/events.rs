@@ -0,0 +1,303 @@
+//! Typed events emitted by the infuser. Each is emitted as a custom `wasm-<type>` event,
+//! so indexers can rebuild infusion state from events alone.
+use cosmwasm_std::{Addr, BankMsg, Coin, CosmosMsg, Event};
+
+use crate::{nfts::NFT, state::IngredientDisposition};
+
+pub const CREATE_EVENT: &str = "infusion-create";
+pub const INFUSE_EVENT: &str = "infusion-infuse";
+pub const BURN_EVENT: &str = "infusion-burn";
+pub const MINT_EVENT: &str = "infusion-mint";
+pub const FEE_SPLIT_EVENT: &str = "infusion-fee-split";
+pub const CONFIG_CHANGE_EVENT: &str = "infusion-config";
+pub const INFUSION_UPDATE_EVENT: &str = "infusion-update";
+pub const WAVS_CREDIT_EVENT: &str = "infusion-wavs-credit";
+pub const FEE_CLAIM_EVENT: &str = "infusion-fee-claim";
+pub const ROYALTY_RECIPIENT_EVENT: &str = "infusion-royalty-recipient";
+pub const PENDING_BUNDLE_EVENT: &str = "infusion-pending-bundle";
+pub const ROYALTY_SKIPPED_EVENT: &str = "infusion-royalty-skipped";
+pub const UNINFUSE_EVENT: &str = "infusion-uninfuse";
+
+/// A new infusion was created.
+pub struct CreateEvent<'a> {
+    pub infusion_id: u64,
+    pub owner: &'a Addr,
+    pub infused_collection: &'a str,
+    pub payment_recipient: &'a Addr,
+    pub num_tokens: u32,
+    pub collections: Vec<&'a Addr>,
+}
+
+impl From<CreateEvent<'_>> for Event {
+    fn from(e: CreateEvent) -> Self {
+        Event::new(CREATE_EVENT)
+            .add_attribute("infusion_id", e.infusion_id.to_string())
+            .add_attribute("owner", e.owner)
+            .add_attribute("infused_collection", e.infused_collection)
+            .add_attribute("payment_recipient", e.payment_recipient)
+            .add_attribute("num_tokens", e.num_tokens.to_string())
+            .add_attribute("collections", join(e.collections))
+    }
+}
+
+/// Bundles were infused, minting `minted` tokens to `recipient`.
+pub struct InfuseEvent<'a> {
+    pub infusion_id: u64,
+    pub sender: &'a Addr,
+    pub recipient: &'a Addr,
+    pub bundles: u64,
+    pub minted: u64,
+}
+
+impl From<InfuseEvent<'_>> for Event {
+    fn from(e: InfuseEvent) -> Self {
+        Event::new(INFUSE_EVENT)
+            .add_attribute("infusion_id", e.infusion_id.to_string())
+            .add_attribute("sender", e.sender)
+            .add_attribute("recipient", e.recipient)
+            .add_attribute("bundles", e.bundles.to_string())
+            .add_attribute("minted", e.minted.to_string())
+    }
+}
+
+/// An nft was consumed by an infusion, and either burnt, vaulted or locked.
+pub struct BurnEvent<'a> {
+    pub infusion_id: u64,
+    pub burner: &'a Addr,
+    pub nft: &'a NFT,
+    pub disposition: &'a IngredientDisposition,
+}
+
+impl From<BurnEvent<'_>> for Event {
+    fn from(e: BurnEvent) -> Self {
+        let disposition = match e.disposition {
+            IngredientDisposition::Burn => "burn".to_string(),
+            IngredientDisposition::Vault { addr } => format!("vault:{addr}"),
+            IngredientDisposition::Lock => "lock".to_string(),
+        };
+        Event::new(BURN_EVENT)
+            .add_attribute("infusion_id", e.infusion_id.to_string())
+            .add_attribute("burner", e.burner)
+            .add_attribute("collection", &e.nft.addr)
+            .add_attribute("token_id", e.nft.token_id.to_string())
+            .add_attribute("disposition", disposition)
+    }
+}
+
+/// An infused token was burned by its owner, returning or burning the locked nfts used to mint it.
+/// One `returned` or `burned` attribute per nft, formatted as `<collection>:<token_id>`.
+pub struct UninfuseEvent<'a> {
+    pub infusion_id: u64,
+    pub owner: &'a Addr,
+    pub infused_collection: &'a str,
+    pub token_id: u32,
+    pub returned: Vec<&'a NFT>,
+    pub burned: Vec<&'a NFT>,
+}
+
+impl From<UninfuseEvent<'_>> for Event {
+    fn from(e: UninfuseEvent) -> Self {
+        let mut event = Event::new(UNINFUSE_EVENT)
+            .add_attribute("infusion_id", e.infusion_id.to_string())
+            .add_attribute("owner", e.owner)
+            .add_attribute("infused_collection", e.infused_collection)
+            .add_attribute("token_id", e.token_id.to_string());
+        for nft in e.returned {
+            event = event.add_attribute("returned", format!("{}:{}", nft.addr, nft.token_id));
+        }
+        for nft in e.burned {
+            event = event.add_attribute("burned", format!("{}:{}", nft.addr, nft.token_id));
+        }
+        event
+    }
+}
+
+/// An infused token was minted.
+pub struct MintEvent<'a> {
+    pub infusion_id: u64,
+    pub infused_collection: &'a str,
+    pub token_id: u32,
+    pub recipient: &'a Addr,
+}
+
+impl From<MintEvent<'_>> for Event {
+    fn from(e: MintEvent) -> Self {
+        Event::new(MINT_EVENT)
+            .add_attribute("infusion_id", e.infusion_id.to_string())
+            .add_attribute("infused_collection", e.infused_collection)
+            .add_attribute("token_id", e.token_id.to_string())
+            .add_attribute("recipient", e.recipient)
+    }
+}
+
+/// Fees paid out, one `payment` attribute per transfer formatted as `<recipient>:<amount>`.
+/// `kind` is one of `creation`, `mint` or `redemption`.
+pub struct FeeSplitEvent<'a> {
+    pub infusion_id: Option<u64>,
+    pub kind: &'a str,
+    pub payments: Vec<(String, Coin)>,
+}
+
+impl<'a> FeeSplitEvent<'a> {
+    /// Collects the bank transfers of a set of fee messages.
+    pub fn from_msgs(infusion_id: Option<u64>, kind: &'a str, msgs: &[CosmosMsg]) -> Self {
+        let payments = msgs
+            .iter()
+            .flat_map(|msg| match msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => amount
+                    .iter()
+                    .map(|c| (to_address.clone(), c.clone()))
+                    .collect(),
+                _ => vec![],
+            })
+            .collect();
+        FeeSplitEvent {
+            infusion_id,
+            kind,
+            payments,
+        }
+    }
+}
+
+impl From<FeeSplitEvent<'_>> for Event {
+    fn from(e: FeeSplitEvent) -> Self {
+        let mut event = Event::new(FEE_SPLIT_EVENT).add_attribute("kind", e.kind);
+        if let Some(id) = e.infusion_id {
+            event = event.add_attribute("infusion_id", id.to_string());
+        }
+        for (recipient, amount) in e.payments {
+            event = event.add_attribute("payment", format!("{recipient}:{amount}"));
+        }
+        event
+    }
+}
+
+/// Global contract config was set, listing each changed field & its new value.
+pub struct ConfigChangeEvent<'a> {
+    pub sender: &'a Addr,
+    pub changes: Vec<(&'a str, String)>,
+}
+
+impl From<ConfigChangeEvent<'_>> for Event {
+    fn from(e: ConfigChangeEvent) -> Self {
+        Event::new(CONFIG_CHANGE_EVENT)
+            .add_attribute("sender", e.sender)
+            .add_attributes(e.changes)
+    }
+}
+
+/// An infusion parameter was updated by its owner, or its mintable tokens were shuffled.
+/// `field` is the updated parameter.
+pub struct InfusionUpdateEvent<'a> {
+    pub infusion_id: u64,
+    pub sender: &'a Addr,
+    pub field: &'a str,
+    pub value: String,
+}
+
+impl From<InfusionUpdateEvent<'_>> for Event {
+    fn from(e: InfusionUpdateEvent) -> Self {
+        Event::new(INFUSION_UPDATE_EVENT)
+            .add_attribute("infusion_id", e.infusion_id.to_string())
+            .add_attribute("sender", e.sender)
+            .add_attribute("field", e.field)
+            .add_attribute("value", e.value)
+    }
+}
+
+/// WAVS operators credited a burner with nfts burnt outside the infuser.
+pub struct WavsCreditEvent<'a> {
+    pub burner: &'a str,
+    pub collection: &'a str,
+    pub credited: u64,
+    pub total: u64,
+}
+
+impl From<WavsCreditEvent<'_>> for Event {
+    fn from(e: WavsCreditEvent) -> Self {
+        Event::new(WAVS_CREDIT_EVENT)
+            .add_attribute("burner", e.burner)
+            .add_attribute("collection", e.collection)
+            .add_attribute("credited", e.credited.to_string())
+            .add_attribute("total", e.total.to_string())
+    }
+}
+
+/// Escrowed fees were withdrawn by their recipient.
+pub struct FeeClaimEvent<'a> {
+    pub recipient: &'a Addr,
+    pub amount: &'a [Coin],
+}
+
+impl From<FeeClaimEvent<'_>> for Event {
+    fn from(e: FeeClaimEvent) -> Self {
+        let mut event = Event::new(FEE_CLAIM_EVENT).add_attribute("recipient", e.recipient);
+        for c in e.amount {
+            event = event.add_attribute("amount", c.to_string());
+        }
+        event
+    }
+}
+
+/// The source royalty recipient of a collection was registered, or removed if `recipient` is unset.
+pub struct RoyaltyRecipientEvent<'a> {
+    pub collection: &'a Addr,
+    pub sender: &'a Addr,
+    pub recipient: Option<&'a Addr>,
+}
+
+impl From<RoyaltyRecipientEvent<'_>> for Event {
+    fn from(e: RoyaltyRecipientEvent) -> Self {
+        let mut event = Event::new(ROYALTY_RECIPIENT_EVENT)
+            .add_attribute("collection", e.collection)
+            .add_attribute("sender", e.sender);
+        if let Some(recipient) = e.recipient {
+            event = event.add_attribute("recipient", recipient);
+        }
+        event
+    }
+}
+
+/// The source royalty of a collection was not paid, as no valid recipient could be found.
+pub struct RoyaltySkippedEvent<'a> {
+    pub collection: &'a Addr,
+    pub reason: String,
+}
+
+impl From<RoyaltySkippedEvent<'_>> for Event {
+    fn from(e: RoyaltySkippedEvent) -> Self {
+        Event::new(ROYALTY_SKIPPED_EVENT)
+            .add_attribute("collection", e.collection)
+            .add_attribute("reason", e.reason)
+    }
+}
+
+/// Nfts were added to, or withdrawn from, a depositors pending bundle.
+/// `kind` is one of `add` or `withdraw`.
+pub struct PendingBundleEvent<'a> {
+    pub infusion_id: u64,
+    pub depositor: &'a Addr,
+    pub kind: &'a str,
+    pub nfts: &'a [NFT],
+}
+
+impl From<PendingBundleEvent<'_>> for Event {
+    fn from(e: PendingBundleEvent) -> Self {
+        let mut event = Event::new(PENDING_BUNDLE_EVENT)
+            .add_attribute("infusion_id", e.infusion_id.to_string())
+            .add_attribute("depositor", e.depositor)
+            .add_attribute("kind", e.kind);
+        for nft in e.nfts {
+            event = event.add_attribute("nft", format!("{}:{}", nft.addr, nft.token_id));
+        }
+        event
+    }
+}
+
+fn join(addrs: Vec<&Addr>) -> String {
+    addrs
+        .iter()
+        .map(|a| a.as_str())
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -1,6 +1,9 @@
+pub mod assets;
 pub mod bundles;
 pub mod create;
+pub mod events;
 pub mod nfts;
+pub mod pricing;
 pub mod state;
 pub mod wavs;
 
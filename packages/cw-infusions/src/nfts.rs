@@ -1,4 +1,4 @@
-use cosmwasm_std::{Addr, Decimal, Timestamp};
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
 
 #[cosmwasm_schema::cw_serde]
 pub struct NFT {
@@ -35,13 +35,32 @@ pub struct InfusedCollection {
     pub addr: Option<String>,
 }
 
+/// cw2981 royalty query, wrapped in the cw721 `Extension` query.
+#[cosmwasm_schema::cw_serde]
+pub enum Cw2981QueryMsg {
+    Extension { msg: Cw2981Query },
+}
+
+#[cosmwasm_schema::cw_serde]
+pub enum Cw2981Query {
+    RoyaltyInfo {
+        token_id: String,
+        sale_price: Uint128,
+    },
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct Cw2981RoyaltiesInfoResponse {
+    pub address: String,
+    pub royalty_amount: Uint128,
+}
+
 #[cosmwasm_schema::cw_serde]
 pub struct RoyaltyInfoResponse {
     pub payment_address: String,
     pub share: Decimal,
 }
 
-
 #[cosmwasm_schema::cw_serde]
 pub struct CollectionInfo<T> {
     pub creator: String,
@@ -0,0 +1,153 @@
+use cosmwasm_std::{
+    Addr, Decimal, Fraction, QuerierWrapper, StdError, StdResult, Timestamp, Uint128,
+};
+
+/// Pricing strategy of an infusions mint fee, with the mint fee amount being the floor price.
+#[cosmwasm_schema::cw_serde]
+pub enum PricingStrategy {
+    /// Price falls from `start_price` to the floor price over `duration` seconds from `start_time`.
+    DutchAuction {
+        start_price: Uint128,
+        start_time: Timestamp,
+        duration: u64,
+        decay: Decay,
+    },
+    /// Price rises from the floor price to `max_price` as the infused collection mints out,
+    /// following `(minted / total supply) ^ exponent`, with a non-zero `exponent`.
+    BondingCurve { max_price: Uint128, exponent: u32 },
+    /// Price is derived from a target value, using the price of the mint fee denom from an oracle.
+    /// Senders bound the fee charged by the amount they send, with any excess refunded.
+    Oracle(OraclePricing),
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct OraclePricing {
+    /// oracle contract implementing `OracleQueryMsg`
+    pub oracle: Addr,
+    /// value of the mint fee, in the unit the oracle prices the denom in
+    pub target_value: Uint128,
+    /// maximum age of the oracle price in seconds
+    pub max_staleness: u64,
+}
+
+/// Query interface oracle contracts must implement.
+#[cosmwasm_schema::cw_serde]
+#[derive(cosmwasm_schema::QueryResponses)]
+pub enum OracleQueryMsg {
+    #[returns(OraclePriceResponse)]
+    Price { denom: String },
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct OraclePriceResponse {
+    /// value of a single unit of the denom
+    pub price: Decimal,
+    pub updated_at: Timestamp,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub enum Decay {
+    Linear,
+    /// Price above the floor halves every `half_life` seconds.
+    Exponential {
+        half_life: u64,
+    },
+}
+
+impl PricingStrategy {
+    /// Returns the current price, given the floor price & minting progress of the infused collection.
+    /// Oracle prices are queried for `denom`.
+    pub fn price(
+        &self,
+        querier: QuerierWrapper,
+        denom: &str,
+        floor: Uint128,
+        now: Timestamp,
+        minted: u32,
+        total: u32,
+    ) -> StdResult<Uint128> {
+        match self {
+            PricingStrategy::DutchAuction {
+                start_price,
+                start_time,
+                duration,
+                decay,
+            } => {
+                let diff = start_price.saturating_sub(floor);
+                let elapsed = now.seconds().saturating_sub(start_time.seconds());
+                if elapsed >= *duration {
+                    return Ok(floor);
+                }
+                let above_floor = match decay {
+                    Decay::Linear => diff - diff.multiply_ratio(elapsed, *duration),
+                    Decay::Exponential { half_life } => {
+                        let halvings = elapsed / half_life;
+                        if halvings >= 128 {
+                            Uint128::zero()
+                        } else {
+                            // interpolate linearly between halvings
+                            let high = diff >> (halvings as u32);
+                            let low = high >> 1;
+                            high - (high - low).multiply_ratio(elapsed % half_life, *half_life)
+                        }
+                    }
+                };
+                Ok(floor + above_floor)
+            }
+            PricingStrategy::BondingCurve {
+                max_price,
+                exponent,
+            } => {
+                if total == 0 {
+                    return Ok(floor);
+                }
+                let progress = Decimal::from_ratio(minted.min(total), total)
+                    .checked_pow(*exponent)
+                    .map_err(StdError::from)?;
+                let diff = max_price.saturating_sub(floor);
+                Ok(floor + diff.multiply_ratio(progress.numerator(), progress.denominator()))
+            }
+            PricingStrategy::Oracle(oracle) => oracle.price(querier, denom, floor, now),
+        }
+    }
+}
+
+impl OraclePricing {
+    /// Queries the oracle for the price of `denom`, returning the amount worth the target value.
+    pub fn price(
+        &self,
+        querier: QuerierWrapper,
+        denom: &str,
+        floor: Uint128,
+        now: Timestamp,
+    ) -> StdResult<Uint128> {
+        let res: OraclePriceResponse = querier.query_wasm_smart(
+            &self.oracle,
+            &OracleQueryMsg::Price {
+                denom: denom.to_string(),
+            },
+        )?;
+        if res.updated_at > now {
+            return Err(StdError::generic_err(format!(
+                "oracle price of {denom} is from the future. Updated at: {}",
+                res.updated_at
+            )));
+        }
+        if now.seconds() - res.updated_at.seconds() > self.max_staleness {
+            return Err(StdError::generic_err(format!(
+                "oracle price of {denom} is stale. Updated at: {}",
+                res.updated_at
+            )));
+        }
+        if res.price.is_zero() {
+            return Err(StdError::generic_err(format!(
+                "oracle price of {denom} is zero"
+            )));
+        }
+        let amount = self
+            .target_value
+            .checked_multiply_ratio(res.price.denominator(), res.price.numerator())
+            .map_err(|e| StdError::generic_err(e.to_string()))?;
+        Ok(amount.max(floor))
+    }
+}
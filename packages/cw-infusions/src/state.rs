@@ -1,6 +1,9 @@
-use cosmwasm_std::{Addr, Coin};
+use cosmwasm_std::{Addr, Decimal, Uint128};
 
-use crate::{bundles::BundleType, nfts::InfusedCollection, BurnParams};
+use crate::{
+    assets::Asset, bundles::BundleType, nfts::InfusedCollection, pricing::PricingStrategy,
+    BurnParams,
+};
 
 #[cosmwasm_schema::cw_serde]
 pub struct Infusion {
@@ -15,6 +18,10 @@ pub struct Infusion {
     /// Parameters of a specific infusion
     pub infusion_params: InfusionParamState,
     pub payment_recipient: Option<Addr>,
+    /// Optional weighted recipients of mint fees & payment substitutes, summing to 100%.
+    /// Defaults to the payment recipient.
+    #[serde(default)]
+    pub fee_recipients: Option<Vec<FeeRecipient>>,
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -28,15 +35,87 @@ pub struct InfusionState {
     /// Parameters of a specific infusion
     pub infusion_params: InfusionParamState,
     pub payment_recipient: Addr,
+    /// Weighted recipients of mint fees & payment substitutes. Empty sends all to the payment recipient.
+    #[serde(default)]
+    pub fee_recipients: Vec<FeeRecipient>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct FeeRecipient {
+    pub addr: Addr,
+    /// Share of fees remaining after the contract owner fee.
+    pub weight: Decimal,
 }
 
 #[cosmwasm_schema::cw_serde]
 pub struct InfusionParamState {
     pub bundle_type: BundleType,
     /// required alongside a bundle. Sent to infusion owner
-    pub mint_fee: Option<Coin>,
+    pub mint_fee: Option<Asset>,
     pub params: Option<BurnParams>,
     pub wavs_enabled: bool,
+    /// What happens to the nfts in a bundle once infused. Defaults to burning them.
+    #[serde(default)]
+    pub disposition: IngredientDisposition,
+    /// If set, infused tokens can be burned to redeem the nfts escrowed to create them.
+    /// Requires the lock or vault ingredient disposition.
+    #[serde(default)]
+    pub redemption: Option<RedemptionParams>,
+    /// Fungible tokens burned alongside the nfts of a bundle. Required by AllOf bundles,
+    /// while each satisfied counts as one eligible ingredient of AnyOf bundles.
+    #[serde(default)]
+    pub fungible_ingredients: Vec<FungibleIngredient>,
+    /// Optional dynamic pricing of the mint fee. Requires a mint fee, used as the floor price.
+    #[serde(default)]
+    pub pricing: Option<PricingStrategy>,
+    /// Mint fee discounts for holders of other collections. The largest discount held applies.
+    #[serde(default)]
+    pub fee_discounts: Vec<FeeDiscount>,
+    /// Share of the mint fee sent to the referrer of an infuse, if one is provided.
+    #[serde(default)]
+    pub referral_share: Option<Decimal>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct FeeDiscount {
+    /// collection address holders are rewarded for
+    pub collection: Addr,
+    /// minimum tokens of the collection the sender must hold. At most 100.
+    pub min_held: u32,
+    /// fraction of the mint fee discounted, up to 100%
+    pub discount: Decimal,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct FungibleIngredient {
+    /// Native or cw20 token & the minimum amount burned per bundle.
+    /// The ingredient is only satisfied if this amount is left in the funds sent.
+    pub min_req: Asset,
+    /// Optional, maximum amount burned per bundle from the funds left.
+    ///  If not set, contract burns exactly min_req per bundle.
+    pub max_req: Option<Uint128>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct RedemptionParams {
+    /// Optional native or cw20 fee required to redeem an infused token. Sent to infusion owner
+    pub fee: Option<Asset>,
+    /// Eligible collections whose escrowed nfts are returned on redemption. Returns all if not set.
+    /// Escrowed nfts not returned are burned.
+    pub collections: Option<Vec<Addr>>,
+}
+
+#[cosmwasm_schema::cw_serde]
+#[derive(Default)]
+pub enum IngredientDisposition {
+    /// Nfts in a bundle are burned.
+    #[default]
+    Burn,
+    /// Nfts in a bundle are transferred to a vault address. They are reclaimed on redemption
+    /// if the vault approves the infuser as an operator of their collections.
+    Vault { addr: Addr },
+    /// Nfts in a bundle are held by the infuser, with a record of who deposited them.
+    Lock,
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -49,7 +128,11 @@ pub struct EligibleNFTCollection {
     ///  If not set, contract expects exact # of min_req per collection in bundle.
     pub max_req: Option<u64>,
     /// If set, infuser can send exact amount of tokens to replace eligil
-    pub payment_substitute: Option<Coin>,
+    pub payment_substitute: Option<Asset>,
+    /// Optional share of the mint fee paid to the collections creator when its nfts are infused.
+    /// Paid to the recipient registered for the collection, or its cw2981 royalty address.
+    #[serde(default)]
+    pub source_royalty: Option<Decimal>,
 }
 
 impl PartialEq<String> for EligibleNFTCollection {
@@ -1,3 +1,5 @@
+use cosmwasm_std::{to_json_vec, Addr, Binary, StdResult};
+
 #[cosmwasm_schema::cw_serde]
 pub struct WavsBundle {
     /// Address of the account that infused the nfts
@@ -40,3 +42,55 @@ pub struct WavsMintCountResponse {
     pub to_mint: u64,
     pub remaining: u64,
 }
+
+#[cosmwasm_schema::cw_serde]
+pub enum WavsKeyType {
+    Secp256k1,
+    Ed25519,
+}
+
+/// Public key of a WAVS operator allowed to sign entry point submissions.
+#[cosmwasm_schema::cw_serde]
+pub struct WavsOperator {
+    pub key_type: WavsKeyType,
+    pub public_key: Binary,
+}
+
+/// Signature of a WAVS operator over `wavs_signing_hash` of a submission.
+#[cosmwasm_schema::cw_serde]
+pub struct WavsSignature {
+    pub public_key: Binary,
+    pub signature: Binary,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct WavsOperatorsResponse {
+    pub operators: Vec<WavsOperator>,
+    /// signatures from distinct operators required per submission
+    pub threshold: u32,
+}
+
+/// Submission signed by WAVS operators. Bound to an infuser & chain so signatures cannot be reused elsewhere.
+#[cosmwasm_schema::cw_serde]
+struct WavsSignedPayload {
+    contract: Addr,
+    chain_id: String,
+    nonce: u64,
+    infusions: Vec<WavsBundle>,
+}
+
+/// Returns the sha256 hash operators sign for a submission of `infusions` to an infuser.
+pub fn wavs_signing_hash(
+    contract: &Addr,
+    chain_id: &str,
+    nonce: u64,
+    infusions: &[WavsBundle],
+) -> StdResult<Vec<u8>> {
+    let payload = to_json_vec(&WavsSignedPayload {
+        contract: contract.clone(),
+        chain_id: chain_id.to_string(),
+        nonce,
+        infusions: infusions.to_vec(),
+    })?;
+    Ok(<sha2::Sha256 as sha2::Digest>::digest(payload).to_vec())
+}
@@ -1,2 +1,3 @@
 pub mod init;
+pub mod mock_oracle;
 pub mod payments;
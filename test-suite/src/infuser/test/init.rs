@@ -1,21 +1,36 @@
 use abstract_cw_multi_test::Contract;
-use cosmwasm_std::{coin, coins, Decimal, Event, Fraction, HexBinary, Uint128};
+use cosmwasm_std::{
+    coin, coins, to_json_binary, BankMsg, Binary, Coin, CosmosMsg, Decimal, Event, Fraction,
+    HexBinary, StdError, Timestamp, Uint128, WasmMsg,
+};
 use cw_infusion_minter::{
-    msg::{ExecuteMsg, ExecuteMsgFns, InstantiateMsg, QueryMsgFns},
-    state::Config,
+    msg::{
+        CollectionInfusions, ExecuteMsg, ExecuteMsgFns, InfusionSummary, InfusionsResponse,
+        InstantiateMsg, ProvenanceEntry, QueryMsg, QueryMsgFns, QueryOrder, ReceiveCw20Msg,
+        ReceiveNftMsg, ReferralStatsResponse, SimulateInfuseResponse,
+    },
+    state::{Config, UpdatingConfig},
     AnyOfErr, ContractError,
 };
 use cw_infusions::{
+    assets::Asset,
     bundles::{Bundle, BundleType},
     nfts::{InfusedCollection, NFT},
-    state::{EligibleNFTCollection, Infusion, InfusionParamState},
-    wavs::WavsBundle,
+    pricing::{Decay, OraclePricing, PricingStrategy},
+    state::{
+        EligibleNFTCollection, FeeDiscount, FeeRecipient, FungibleIngredient, Infusion,
+        InfusionParamState, IngredientDisposition, RedemptionParams,
+    },
+    wavs::{wavs_signing_hash, WavsBundle, WavsKeyType, WavsOperator, WavsSignature},
 };
+use ed25519_dalek::{Signer, SigningKey};
 use std::{error::Error, str::FromStr};
 // Use prelude to get all the necessary imports
 use cw_infuser_scripts::CwInfuser;
 use cw_orch::{anyhow, prelude::*};
 
+use super::mock_oracle::{mock_oracle_contract, MockOracleExecuteMsg};
+
 fn cw721_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
         cw721_base::entry::execute,
@@ -25,6 +40,15 @@ fn cw721_contract() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+fn cw20_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        cw20_base::contract::execute,
+        cw20_base::contract::instantiate,
+        cw20_base::contract::query,
+    );
+    Box::new(contract)
+}
+
 // fn v020_infusion() -> Box<dyn Contract<Empty>> {
 //     let contract = ContractWrapper::new(
 //         v020infuse::contract::execute,
@@ -36,6 +60,37 @@ fn cw721_contract() -> Box<dyn Contract<Empty>> {
 //     Box::new(contract)
 // }
 
+// ed25519 wavs operator of a signing key
+fn wavs_operator(key: &SigningKey) -> WavsOperator {
+    WavsOperator {
+        key_type: WavsKeyType::Ed25519,
+        public_key: Binary::from(key.verifying_key().to_bytes().to_vec()),
+    }
+}
+
+// submits wavs bundles signed by `key`, using the next nonce of the operator
+fn wavs_submit(
+    app: &CwInfuser<MockBech32>,
+    key: &SigningKey,
+    bundles: Vec<WavsBundle>,
+) -> Result<TxResponse<MockBech32>, CwOrchError> {
+    let public_key = wavs_operator(key).public_key;
+    let nonce = app.wavs_operator_nonce(public_key.clone())? + 1;
+    let chain_id = app.environment().block_info()?.chain_id;
+    let hash = wavs_signing_hash(&app.address()?, &chain_id, nonce, &bundles)?;
+    app.execute(
+        &ExecuteMsg::WavsSignedEntryPoint {
+            infusions: bundles,
+            nonce,
+            signatures: vec![WavsSignature {
+                public_key,
+                signature: Binary::from(key.sign(&hash).to_bytes().to_vec()),
+            }],
+        },
+        None,
+    )
+}
+
 // minimal infuser
 pub struct InfuserSuite<Chain> {
     pub chain: MockBech32,
@@ -43,7 +98,7 @@ pub struct InfuserSuite<Chain> {
     pub nfts: Vec<Addr>,
     pub infusion: Infusion,
     pub admin: Addr,
-    pub wavs_service: Addr,
+    pub wavs_operator: SigningKey,
     pub payment_recipient: Addr,
 }
 
@@ -74,12 +129,14 @@ impl<Chain: CwEnv> InfuserSuite<Chain> {
                 min_req: 1,
                 max_req: None,
                 payment_substitute: None,
+                source_royalty: None,
             },
             EligibleNFTCollection {
                 addr: nfts[1].clone(),
                 min_req: 1,
                 max_req: None,
                 payment_substitute: None,
+                source_royalty: None,
             },
         ];
         Ok(good_nfts)
@@ -211,15 +268,17 @@ impl<Chain: CwEnv> InfuserSuite<Chain> {
             &InstantiateMsg {
                 contract_owner: Some(env.admin.to_string()),
                 owner_fee: Decimal::from_str("0.1")?,
-                min_creation_fee: Some(coin(500u128, "ustars")),
-                min_infusion_fee: Some(coin(100u128, "ustars")),
+                min_creation_fee: Some(coin(500u128, "ustars").into()),
+                accepted_creation_fees: None,
+                escrow_fees: None,
+                min_infusion_fee: Some(coin(100u128, "ustars").into()),
                 min_per_bundle: None,
                 max_per_bundle: None,
                 max_bundles: None,
                 max_infusions: None,
                 cw721_code_id: 2u64,
                 wavs_public_key: match wavs_public_key {
-                    true => Some(env.wavs_service.to_string()),
+                    true => Some(wavs_operator(&env.wavs_operator)),
                     false => None,
                 },
             },
@@ -242,17 +301,24 @@ impl<Chain: CwEnv> InfuserSuite<Chain> {
             mint_fee: None,
             bundle_type,
             wavs_enabled: false,
+            disposition: IngredientDisposition::Burn,
+            redemption: None,
+            fungible_ingredients: vec![],
+            pricing: None,
+            fee_discounts: vec![],
+            referral_share: None,
         };
 
         let good_infused = InfuserSuite::<MockBech32>::default_infused_collection()?;
         // ensure fee set is within contract level bounds
-        infusion_params.mint_fee = Some(coin(100, "ustars"));
+        infusion_params.mint_fee = Some(coin(100, "ustars").into());
 
         let infusion = Infusion {
             collections: good_nfts.clone(),
             infused_collection: good_infused.clone(),
             infusion_params,
             payment_recipient: Some(payment_recipient.clone()),
+            fee_recipients: None,
             owner: None,
             description: Some("testewates".to_string()),
         };
@@ -263,10 +329,47 @@ impl<Chain: CwEnv> InfuserSuite<Chain> {
             infusion,
             admin: env.admin,
             payment_recipient,
-            wavs_service: env.wavs_service,
+            wavs_operator: env.wavs_operator,
         })
     }
 
+    // creates the suite infusion, paying the fee suite creation fee, & returns its id
+    fn create_infusion(&self) -> anyhow::Result<u64> {
+        let res = self.infuser.execute(
+            &ExecuteMsg::CreateInfusion {
+                infusions: vec![self.infusion.clone()],
+            },
+            Some(&[coin(500, "ustars")]),
+        )?;
+        Ok(Uint128::from_str(&res.event_attr_value("wasm", "infusion-id")?)?.u128() as u64)
+    }
+
+    // bundle of `token_id` from both eligible collections
+    fn bundle(&self, token_id: u64) -> Bundle {
+        Bundle {
+            nfts: vec![
+                NFT {
+                    addr: self.nfts[0].clone(),
+                    token_id,
+                },
+                NFT {
+                    addr: self.nfts[1].clone(),
+                    token_id,
+                },
+            ],
+        }
+    }
+
+    // infuses the bundle of `token_id`, minting to the sender without a referrer
+    fn infuse_msg(&self, id: u64, token_id: u64) -> ExecuteMsg {
+        ExecuteMsg::Infuse {
+            id,
+            bundle: vec![self.bundle(token_id)],
+            recipient: None,
+            referrer: None,
+        }
+    }
+
     // setsup the infuser suite by storing, instantiating, and configuring nft collections & the cw-infuser
     fn setup() -> anyhow::Result<InfuserSuite<MockBech32>> {
         let mock = MockBech32::new("mock");
@@ -275,7 +378,7 @@ impl<Chain: CwEnv> InfuserSuite<Chain> {
         mock.add_balance(&sender, coins(100000000, "ubtsg"))?;
         mock.add_balance(&sender, coins(100000000, "ustars"))?;
         let treasury = mock.addr_make("treasury");
-        let wavs_service = mock.addr_make("wavs_service");
+        let wavs_operator = SigningKey::from_bytes(&[9u8; 32]);
 
         // latest infusion version
         let infuser = CwInfuser::new(mock.clone());
@@ -302,8 +405,10 @@ impl<Chain: CwEnv> InfuserSuite<Chain> {
             cw721_code_id,
             owner_fee: Decimal::zero(),
             min_creation_fee: None,
+            accepted_creation_fees: None,
+            escrow_fees: None,
             min_infusion_fee: None,
-            wavs_public_key: Some(wavs_service.to_string()),
+            wavs_public_key: Some(wavs_operator(&wavs_operator)),
         };
 
         // create cw-infsion app
@@ -325,8 +430,15 @@ impl<Chain: CwEnv> InfuserSuite<Chain> {
                 mint_fee: None,
                 bundle_type: BundleType::AllOf {},
                 wavs_enabled: false,
+                disposition: IngredientDisposition::Burn,
+                redemption: None,
+                fungible_ingredients: vec![],
+                pricing: None,
+                fee_discounts: vec![],
+                referral_share: None,
             },
             payment_recipient: Some(treasury.clone()),
+            fee_recipients: None,
             owner: Some(admin.clone()),
             description: Some("testewates".to_string()),
         };
@@ -337,6 +449,7 @@ impl<Chain: CwEnv> InfuserSuite<Chain> {
                 min_req: 2,
                 max_req: None,
                 payment_substitute: None,
+                source_royalty: None,
             };
             infusion.infused_collection.name =
                 infusion.infused_collection.name + &i.to_string().to_owned();
@@ -357,112 +470,2337 @@ impl<Chain: CwEnv> InfuserSuite<Chain> {
             infusion,
             admin,
             payment_recipient: treasury,
-            wavs_service,
+            wavs_operator,
+        })
+    }
+}
+
+#[test]
+fn test_successful_install() -> anyhow::Result<()> {
+    let env = InfuserSuite::<MockBech32>::setup()?;
+    let app = env.infuser;
+
+    let config = app.config()?;
+    assert_eq!(
+        config,
+        Config {
+            latest_infusion_id: 4,
+            contract_owner: env.admin,
+            max_infusions: 2u64,
+            min_per_bundle: 1u64,
+            max_per_bundle: 10u64,
+            max_bundles: 5u64,
+            code_id: 2,
+            code_hash: HexBinary::from_hex(
+                "7e961e9369f7a3619b102834beec5bc2463f9008b40de972c91c45e3b300a805"
+            )?,
+            owner_fee: Decimal::zero(),
+            min_creation_fee: None,
+            accepted_creation_fees: vec![],
+            escrow_fees: false,
+            min_infusion_fee: None,
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn test_successful_infusion() -> anyhow::Result<()> {
+    let env = InfuserSuite::<MockBech32>::setup()?;
+    let app = env.infuser;
+
+    // infuse
+    app.infuse(
+        vec![Bundle {
+            nfts: vec![
+                NFT {
+                    addr: env.nfts[0].clone(),
+                    token_id: 1,
+                },
+                NFT {
+                    addr: env.nfts[0].clone(),
+                    token_id: 3,
+                },
+            ],
+        }],
+        1,
+        None,
+        None,
+    )?;
+    // println!("{:#?}", res);
+
+    // confirm infused collection mint
+    let res = app.infusion_by_id(1)?;
+    assert!(
+        res.infused_collection.addr.is_some(),
+        "infusion collection not set!"
+    );
+    env.chain.wait_blocks(1)?;
+
+    // error if too few nfts provided in bundle
+    let err = app.infuse(vec![], 1, None, None).unwrap_err();
+    assert_eq!(err.source().unwrap().to_string(), "Bundle cannot be empty.");
+
+    // error if too many nfts provided in bundle
+    let err = app
+        .infuse(
+            vec![Bundle {
+                nfts: vec![
+                    NFT {
+                        addr: env.nfts[0].clone(),
+                        token_id: 2,
+                    },
+                    NFT {
+                        addr: env.nfts[0].clone(),
+                        token_id: 4,
+                    },
+                    NFT {
+                        addr: env.nfts[0].clone(),
+                        token_id: 6,
+                    },
+                ],
+            }],
+            1,
+            None,
+            None,
+        )
+        .unwrap_err();
+    println!("{:#?}", err);
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::BundleNotAccepted {
+            have: 3,
+            want: 2,
+            addr: env.nfts[0].to_string(),
+        }
+        .to_string()
+    );
+
+    // assert queries
+    let res = app.infusion_by_id(1)?;
+    assert_eq!(res.collections.len(), 1);
+    assert_eq!(res.collections[0].min_req, 2);
+
+    // todo: finish asserting all queries work as expected
+    Ok(())
+}
+
+#[test]
+fn test_infuse_to_recipient() -> anyhow::Result<()> {
+    let env = InfuserSuite::<MockBech32>::setup()?;
+    let app = env.infuser;
+    let cold_wallet = env.chain.addr_make("cold-wallet");
+
+    app.infuse(
+        vec![Bundle {
+            nfts: vec![
+                NFT {
+                    addr: env.nfts[0].clone(),
+                    token_id: 1,
+                },
+                NFT {
+                    addr: env.nfts[0].clone(),
+                    token_id: 3,
+                },
+            ],
+        }],
+        1,
+        Some(cold_wallet.to_string()),
+        None,
+    )?;
+
+    let infused_col = Addr::unchecked(
+        app.infusion_by_id(1)?
+            .infused_collection
+            .addr
+            .expect("infusion collection not set!"),
+    );
+
+    // infused token is minted to the recipient, not the burner
+    let tokens: cw721::TokensResponse = env.chain.query(
+        &cw721::Cw721QueryMsg::Tokens {
+            owner: cold_wallet.to_string(),
+            start_after: None,
+            limit: None,
+        },
+        &infused_col,
+    )?;
+    assert_eq!(tokens.tokens.len(), 1);
+    let tokens: cw721::TokensResponse = env.chain.query(
+        &cw721::Cw721QueryMsg::Tokens {
+            owner: env.chain.sender_addr().to_string(),
+            start_after: None,
+            limit: None,
+        },
+        &infused_col,
+    )?;
+    assert!(tokens.tokens.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_receive_nft_bundle() -> anyhow::Result<()> {
+    let mut env = InfuserSuite::<MockBech32>::setup()?;
+    let app = env.infuser.clone();
+    let infuser = app.address()?;
+    let sender = env.chain.sender_addr();
+
+    let send_nft = |col: &Addr, token_id: u64, id: u64| {
+        env.chain.execute(
+            &cw721::Cw721ExecuteMsg::SendNft {
+                contract: infuser.to_string(),
+                token_id: token_id.to_string(),
+                msg: to_json_binary(&ReceiveNftMsg::AddToBundle { id }).unwrap(),
+            },
+            &[],
+            col,
+        )
+    };
+
+    // collections not eligible for the infusion are rejected
+    assert!(send_nft(&env.nfts[1], 1, 1).is_err());
+
+    // abandoned pending bundles can be withdrawn
+    let res = send_nft(&env.nfts[1], 5, 2)?;
+    assert_eq!(
+        res.event_attr_value("wasm-infusion-pending-bundle", "kind")?,
+        "add"
+    );
+    let res = app.withdraw_pending_bundle(2)?;
+    assert_eq!(
+        res.event_attr_value("wasm-infusion-pending-bundle", "nft")?,
+        format!("{}:5", env.nfts[1])
+    );
+    let owner: cw721::OwnerOfResponse = env.chain.query(
+        &cw721::Cw721QueryMsg::OwnerOf {
+            token_id: "5".to_string(),
+            include_expired: None,
+        },
+        &env.nfts[1],
+    )?;
+    assert_eq!(owner.owner, sender.to_string());
+    assert_eq!(
+        app.withdraw_pending_bundle(2)
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string(),
+        ContractError::EmptyBundle.to_string()
+    );
+
+    // complete a bundle sent to the infuser
+    send_nft(&env.nfts[0], 1, 1)?;
+    send_nft(&env.nfts[0], 3, 1)?;
+    app.complete_bundle(1, None, None)?;
+
+    let infused_col = Addr::unchecked(
+        app.infusion_by_id(1)?
+            .infused_collection
+            .addr
+            .expect("infusion collection not set!"),
+    );
+    let tokens: cw721::TokensResponse = env.chain.query(
+        &cw721::Cw721QueryMsg::Tokens {
+            owner: sender.to_string(),
+            start_after: None,
+            limit: None,
+        },
+        &infused_col,
+    )?;
+    assert_eq!(tokens.tokens.len(), 1);
+
+    // pending bundle is cleared once complete
+    assert_eq!(
+        app.complete_bundle(1, None, None)
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string(),
+        ContractError::EmptyBundle.to_string()
+    );
+
+    // pending bundles hold at most a single bundles worth of nfts
+    env.infusion.collections = env.nfts[1..3]
+        .iter()
+        .map(|addr| EligibleNFTCollection {
+            addr: addr.clone(),
+            min_req: 1,
+            max_req: None,
+            payment_substitute: None,
+            source_royalty: None,
         })
+        .collect();
+    let res = app.create_infusion(vec![env.infusion.clone()])?;
+    let infusion_id =
+        Uint128::from_str(&res.event_attr_value("wasm", "infusion-id")?)?.u128() as u64;
+    for token_id in 1..=10 {
+        send_nft(&env.nfts[1], token_id, infusion_id)?;
     }
+    assert!(send_nft(&env.nfts[2], 1, infusion_id).is_err());
+    app.withdraw_pending_bundle(infusion_id)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_ingredient_disposition() -> anyhow::Result<()> {
+    let mut env = InfuserSuite::<MockBech32>::setup()?;
+    let app = env.infuser;
+    let vault = env.chain.addr_make("vault");
+    let col = env.infusion.collections[0].addr.clone();
+
+    let owner_of = |token_id: u64| -> anyhow::Result<String> {
+        let res: cw721::OwnerOfResponse = env.chain.query(
+            &cw721::Cw721QueryMsg::OwnerOf {
+                token_id: token_id.to_string(),
+                include_expired: None,
+            },
+            &col,
+        )?;
+        Ok(res.owner)
+    };
+    let bundle = |token_ids: [u64; 2]| Bundle {
+        nfts: token_ids
+            .iter()
+            .map(|token_id| NFT {
+                addr: col.clone(),
+                token_id: *token_id,
+            })
+            .collect(),
+    };
+
+    // locked nfts are held by the infuser
+    env.infusion.infusion_params.disposition = IngredientDisposition::Lock;
+    let res = app.create_infusion(vec![env.infusion.clone()])?;
+    let infusion_id =
+        Uint128::from_str(&res.event_attr_value("wasm", "infusion-id")?)?.u128() as u64;
+    app.infuse(vec![bundle([1, 2])], infusion_id, None, None)?;
+    assert_eq!(owner_of(1)?, app.address()?.to_string());
+    let record = app
+        .escrowed_nft(col.clone(), 1)?
+        .expect("escrow not recorded");
+    assert_eq!(record.depositor, env.chain.sender_addr());
+    assert_eq!(record.infusion_id, infusion_id);
+    env.chain.next_block()?;
+
+    // vaulted nfts are sent to the vault
+    env.infusion.infusion_params.disposition = IngredientDisposition::Vault {
+        addr: vault.clone(),
+    };
+    let res = app.create_infusion(vec![env.infusion.clone()])?;
+    let infusion_id =
+        Uint128::from_str(&res.event_attr_value("wasm", "infusion-id")?)?.u128() as u64;
+    app.infuse(vec![bundle([3, 4])], infusion_id, None, None)?;
+    assert_eq!(owner_of(3)?, vault.to_string());
+    assert_eq!(
+        app.escrowed_nft(col.clone(), 4)?
+            .expect("escrow not recorded")
+            .holder,
+        vault
+    );
+
+    // burned nfts have no escrow record
+    assert!(app.escrowed_nft(col, 5)?.is_none());
+
+    Ok(())
 }
 
-#[test]
-fn test_successful_install() -> anyhow::Result<()> {
-    let env = InfuserSuite::<MockBech32>::setup()?;
-    let app = env.infuser;
+#[test]
+fn test_uninfuse() -> anyhow::Result<()> {
+    let mut env = InfuserSuite::<MockBech32>::setup()?;
+    let app = env.infuser;
+    let sender = env.chain.sender_addr();
+    let col = env.infusion.collections[0].addr.clone();
+
+    // redemption requires nfts locked or vaulted rather than burned
+    env.infusion.infusion_params.redemption = Some(RedemptionParams {
+        fee: None,
+        collections: None,
+    });
+    env.infusion.infusion_params.disposition = IngredientDisposition::Burn;
+    assert_eq!(
+        app.create_infusion(vec![env.infusion.clone()])
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string(),
+        ContractError::RedemptionRequiresEscrow.to_string()
+    );
+
+    env.infusion.infusion_params.disposition = IngredientDisposition::Lock;
+    let res = app.create_infusion(vec![env.infusion.clone()])?;
+    let infusion_id =
+        Uint128::from_str(&res.event_attr_value("wasm", "infusion-id")?)?.u128() as u64;
+    app.infuse(
+        vec![Bundle {
+            nfts: vec![
+                NFT {
+                    addr: col.clone(),
+                    token_id: 1,
+                },
+                NFT {
+                    addr: col.clone(),
+                    token_id: 2,
+                },
+            ],
+        }],
+        infusion_id,
+        None,
+        None,
+    )?;
+
+    let infused_col = Addr::unchecked(
+        app.infusion_by_id(infusion_id)?
+            .infused_collection
+            .addr
+            .expect("infusion collection not set!"),
+    );
+    let tokens: cw721::TokensResponse = env.chain.query(
+        &cw721::Cw721QueryMsg::Tokens {
+            owner: sender.to_string(),
+            start_after: None,
+            limit: None,
+        },
+        &infused_col,
+    )?;
+    let token_id = u32::from_str(&tokens.tokens[0])?;
+    assert_eq!(app.infused_ingredients(infusion_id, token_id)?.len(), 2);
+
+    // infusions without redemption cannot be uninfused
+    assert_eq!(
+        app.uninfuse(4, token_id)
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string(),
+        ContractError::RedemptionDisabled.to_string()
+    );
+
+    // only the infused token owner can redeem
+    assert_eq!(
+        app.call_as(&env.admin)
+            .uninfuse(infusion_id, token_id)
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string(),
+        ContractError::SenderNotOwner {}.to_string()
+    );
+
+    env.chain.execute(
+        &cw721::Cw721ExecuteMsg::Approve {
+            spender: app.address()?.to_string(),
+            token_id: token_id.to_string(),
+            expires: None,
+        },
+        &[],
+        &infused_col,
+    )?;
+    let res = app.uninfuse(infusion_id, token_id)?;
+    assert_eq!(
+        res.event_attr_values("wasm-infusion-uninfuse", "returned"),
+        vec![format!("{col}:1"), format!("{col}:2")]
+    );
+
+    // escrowed nfts are returned & the infused token is burned
+    for id in [1u64, 2] {
+        let owner: cw721::OwnerOfResponse = env.chain.query(
+            &cw721::Cw721QueryMsg::OwnerOf {
+                token_id: id.to_string(),
+                include_expired: None,
+            },
+            &col,
+        )?;
+        assert_eq!(owner.owner, sender.to_string());
+        assert!(app.escrowed_nft(col.clone(), id)?.is_none());
+    }
+    let tokens: cw721::TokensResponse = env.chain.query(
+        &cw721::Cw721QueryMsg::Tokens {
+            owner: sender.to_string(),
+            start_after: None,
+            limit: None,
+        },
+        &infused_col,
+    )?;
+    assert!(tokens.tokens.is_empty());
+
+    // locked nfts not returned are burned, leaving no escrow record behind
+    env.infusion.infusion_params.redemption = Some(RedemptionParams {
+        fee: None,
+        collections: Some(vec![]),
+    });
+    let res = app.create_infusion(vec![env.infusion.clone()])?;
+    let infusion_id =
+        Uint128::from_str(&res.event_attr_value("wasm", "infusion-id")?)?.u128() as u64;
+    app.infuse(
+        vec![Bundle {
+            nfts: vec![
+                NFT {
+                    addr: col.clone(),
+                    token_id: 3,
+                },
+                NFT {
+                    addr: col.clone(),
+                    token_id: 4,
+                },
+            ],
+        }],
+        infusion_id,
+        None,
+        None,
+    )?;
+    let infused_col = Addr::unchecked(
+        app.infusion_by_id(infusion_id)?
+            .infused_collection
+            .addr
+            .expect("infusion collection not set!"),
+    );
+    let tokens: cw721::TokensResponse = env.chain.query(
+        &cw721::Cw721QueryMsg::Tokens {
+            owner: sender.to_string(),
+            start_after: None,
+            limit: None,
+        },
+        &infused_col,
+    )?;
+    let token_id = u32::from_str(&tokens.tokens[0])?;
+    env.chain.execute(
+        &cw721::Cw721ExecuteMsg::Approve {
+            spender: app.address()?.to_string(),
+            token_id: token_id.to_string(),
+            expires: None,
+        },
+        &[],
+        &infused_col,
+    )?;
+    let res = app.uninfuse(infusion_id, token_id)?;
+    assert_eq!(
+        res.event_attr_values("wasm-infusion-uninfuse", "burned"),
+        vec![format!("{col}:3"), format!("{col}:4")]
+    );
+    for id in [3u64, 4] {
+        assert!(env
+            .chain
+            .query::<_, cw721::OwnerOfResponse>(
+                &cw721::Cw721QueryMsg::OwnerOf {
+                    token_id: id.to_string(),
+                    include_expired: None,
+                },
+                &col,
+            )
+            .is_err());
+        assert!(app.escrowed_nft(col.clone(), id)?.is_none());
+    }
+    assert!(app.infused_ingredients(infusion_id, token_id)?.is_empty());
+
+    // vaulted nfts are reclaimed from the vault once it approves the infuser
+    let vault = env.chain.addr_make("vault");
+    env.infusion.infusion_params.disposition = IngredientDisposition::Vault {
+        addr: vault.clone(),
+    };
+    env.infusion.infusion_params.redemption = Some(RedemptionParams {
+        fee: None,
+        collections: None,
+    });
+    let res = app.create_infusion(vec![env.infusion.clone()])?;
+    let infusion_id =
+        Uint128::from_str(&res.event_attr_value("wasm", "infusion-id")?)?.u128() as u64;
+    app.infuse(
+        vec![Bundle {
+            nfts: vec![
+                NFT {
+                    addr: col.clone(),
+                    token_id: 5,
+                },
+                NFT {
+                    addr: col.clone(),
+                    token_id: 6,
+                },
+            ],
+        }],
+        infusion_id,
+        None,
+        None,
+    )?;
+    let infused_col = Addr::unchecked(
+        app.infusion_by_id(infusion_id)?
+            .infused_collection
+            .addr
+            .expect("infusion collection not set!"),
+    );
+    let tokens: cw721::TokensResponse = env.chain.query(
+        &cw721::Cw721QueryMsg::Tokens {
+            owner: sender.to_string(),
+            start_after: None,
+            limit: None,
+        },
+        &infused_col,
+    )?;
+    let token_id = u32::from_str(&tokens.tokens[0])?;
+    env.chain.execute(
+        &cw721::Cw721ExecuteMsg::Approve {
+            spender: app.address()?.to_string(),
+            token_id: token_id.to_string(),
+            expires: None,
+        },
+        &[],
+        &infused_col,
+    )?;
+    assert!(app.uninfuse(infusion_id, token_id).is_err());
+    env.chain.call_as(&vault).execute(
+        &cw721::Cw721ExecuteMsg::ApproveAll {
+            operator: app.address()?.to_string(),
+            expires: None,
+        },
+        &[],
+        &col,
+    )?;
+    let res = app.uninfuse(infusion_id, token_id)?;
+    assert_eq!(
+        res.event_attr_values("wasm-infusion-uninfuse", "returned"),
+        vec![format!("{col}:5"), format!("{col}:6")]
+    );
+    for id in [5u64, 6] {
+        let owner: cw721::OwnerOfResponse = env.chain.query(
+            &cw721::Cw721QueryMsg::OwnerOf {
+                token_id: id.to_string(),
+                include_expired: None,
+            },
+            &col,
+        )?;
+        assert_eq!(owner.owner, sender.to_string());
+        assert!(app.escrowed_nft(col.clone(), id)?.is_none());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_redemption_fee() -> anyhow::Result<()> {
+    let mut env = InfuserSuite::<MockBech32>::setup_fee_suite(BundleType::AllOf {}, vec![], false)?;
+    let app = env.infuser.clone();
+    let cw20_code_id = env
+        .chain
+        .upload_custom("cw20", cw20_contract())?
+        .uploaded_code_id()?;
+    let token = env
+        .chain
+        .instantiate(
+            cw20_code_id,
+            &cw20_base::msg::InstantiateMsg {
+                name: "redemption token".to_string(),
+                symbol: "REDEEM".to_string(),
+                decimals: 6,
+                initial_balances: vec![cw20::Cw20Coin {
+                    address: env.admin.to_string(),
+                    amount: Uint128::new(1000),
+                }],
+                mint: None,
+                marketing: None,
+            },
+            Some("cw20"),
+            None,
+            &[],
+        )?
+        .instantiated_contract_address()?;
+    let cw20_balance = |addr: &Addr| -> anyhow::Result<Uint128> {
+        let res: cw20::BalanceResponse = env.chain.query(
+            &cw20::Cw20QueryMsg::Balance {
+                address: addr.to_string(),
+            },
+            &token,
+        )?;
+        Ok(res.balance)
+    };
+    let infuser = app.address()?;
+    let redeem = |id: u64, token_id: u32| {
+        env.chain.call_as(&env.admin).execute(
+            &cw20::Cw20ExecuteMsg::Send {
+                contract: infuser.to_string(),
+                amount: Uint128::new(50),
+                msg: to_json_binary(&ReceiveCw20Msg::Uninfuse { id, token_id }).unwrap(),
+            },
+            &[],
+            &token,
+        )
+    };
+
+    // the fungible ingredient of an AnyOf bundle mints a second token, with no nfts to redeem
+    env.chain.add_balance(&env.admin, coins(50, "ubtsg"))?;
+    env.infusion.infusion_params.bundle_type = BundleType::AnyOf {
+        addrs: vec![env.nfts[0].clone(), env.nfts[1].clone()],
+    };
+    env.infusion.infusion_params.fungible_ingredients = vec![FungibleIngredient {
+        min_req: coin(50, "ubtsg").into(),
+        max_req: None,
+    }];
+    env.infusion.infusion_params.disposition = IngredientDisposition::Lock;
+    env.infusion.infusion_params.redemption = Some(RedemptionParams {
+        fee: Some(Asset::Cw20 {
+            address: token.clone(),
+            amount: Uint128::new(50),
+        }),
+        collections: None,
+    });
+    let infusion_id = env.create_infusion()?;
+    let res = app.call_as(&env.admin).execute(
+        &ExecuteMsg::Infuse {
+            id: infusion_id,
+            bundle: vec![Bundle {
+                nfts: vec![NFT {
+                    addr: env.nfts[0].clone(),
+                    token_id: 11,
+                }],
+            }],
+            recipient: None,
+            referrer: None,
+        },
+        Some(&[coin(100, "ustars"), coin(50, "ubtsg")]),
+    )?;
+    let minted = res
+        .event_attr_values("wasm-infusion-mint", "token_id")
+        .iter()
+        .map(|id| u32::from_str(id))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(minted.len(), 2);
+    assert_eq!(app.infused_ingredients(infusion_id, minted[0])?.len(), 1);
+    assert!(app.infused_ingredients(infusion_id, minted[1])?.is_empty());
+
+    let infused_col = Addr::unchecked(
+        app.infusion_by_id(infusion_id)?
+            .infused_collection
+            .addr
+            .expect("infusion collection not set!"),
+    );
+    env.chain.call_as(&env.admin).execute(
+        &cw721::Cw721ExecuteMsg::ApproveAll {
+            operator: app.address()?.to_string(),
+            expires: None,
+        },
+        &[],
+        &infused_col,
+    )?;
+    assert_eq!(
+        redeem(infusion_id, minted[1])
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string(),
+        ContractError::NothingToRedeem {
+            token_id: minted[1]
+        }
+        .to_string()
+    );
+
+    // the redemption fee is paid in the cw20 configured
+    let fee_denom = format!("cw20:{token}");
+    assert_eq!(
+        app.call_as(&env.admin)
+            .uninfuse(infusion_id, minted[0])
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string(),
+        ContractError::FeeNotAccepted {
+            have: coin(0, &fee_denom),
+            want: coin(50, &fee_denom),
+        }
+        .to_string()
+    );
+    redeem(infusion_id, minted[0])?;
+    let owner: cw721::OwnerOfResponse = env.chain.query(
+        &cw721::Cw721QueryMsg::OwnerOf {
+            token_id: "11".to_string(),
+            include_expired: None,
+        },
+        &env.nfts[0],
+    )?;
+    assert_eq!(owner.owner, env.admin.to_string());
+    // admin is also the contract owner, receiving 10% of the fee back
+    assert_eq!(cw20_balance(&env.admin)?, Uint128::new(1000 - 45));
+    assert_eq!(cw20_balance(&env.payment_recipient)?, Uint128::new(45));
+    Ok(())
+}
+
+#[test]
+fn test_provenance() -> anyhow::Result<()> {
+    let env = InfuserSuite::<MockBech32>::setup()?;
+    let app = env.infuser;
+    let sender = env.chain.sender_addr();
+
+    let bundle = |a: u64, b: u64| {
+        vec![Bundle {
+            nfts: vec![
+                NFT {
+                    addr: env.nfts[0].clone(),
+                    token_id: a,
+                },
+                NFT {
+                    addr: env.nfts[0].clone(),
+                    token_id: b,
+                },
+            ],
+        }]
+    };
+    app.infuse(bundle(1, 3), 1, None, None)?;
+    env.chain.next_block()?;
+    app.infuse(bundle(5, 7), 1, None, None)?;
+
+    let infused_col = Addr::unchecked(
+        app.infusion_by_id(1)?
+            .infused_collection
+            .addr
+            .expect("infusion collection not set!"),
+    );
+    let tokens: cw721::TokensResponse = env.chain.query(
+        &cw721::Cw721QueryMsg::Tokens {
+            owner: sender.to_string(),
+            start_after: None,
+            limit: None,
+        },
+        &infused_col,
+    )?;
+    assert_eq!(tokens.tokens.len(), 2);
+
+    // each infused token records the nfts burnt to mint it
+    let mut burned = vec![];
+    for token_id in tokens.tokens.iter() {
+        let prov = app
+            .provenance(1, u32::from_str(token_id)?)?
+            .expect("provenance not recorded");
+        assert_eq!(prov.burner, sender);
+        assert!(prov.payment_substitutes.is_empty());
+        assert_eq!(prov.fee, None);
+        burned.extend(prov.burned.iter().map(|n| n.token_id));
+    }
+    burned.sort();
+    assert_eq!(burned, vec![1, 3, 5, 7]);
+
+    // burner history is paginated
+    let history = |start_after: Option<(u64, u32)>| -> anyhow::Result<Vec<ProvenanceEntry>> {
+        Ok(env.chain.query(
+            &QueryMsg::BurnerHistory {
+                addr: sender.clone(),
+                start_after,
+                limit: Some(1),
+            },
+            &app.address()?,
+        )?)
+    };
+    let page = history(None)?;
+    assert_eq!(page.len(), 1);
+    let next = history(Some((page[0].id, page[0].token_id)))?;
+    assert_eq!(next.len(), 1);
+    assert_ne!(page[0].token_id, next[0].token_id);
+    assert!(history(Some((next[0].id, next[0].token_id)))?.is_empty());
+
+    // nothing recorded for others
+    let history: Vec<ProvenanceEntry> = env.chain.query(
+        &QueryMsg::BurnerHistory {
+            addr: env.admin.clone(),
+            start_after: None,
+            limit: None,
+        },
+        &app.address()?,
+    )?;
+    assert!(history.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_simulate_infuse() -> anyhow::Result<()> {
+    let mut env = InfuserSuite::<MockBech32>::setup_fee_suite(BundleType::AllOf {}, vec![], false)?;
+    let app = env.infuser.clone();
+    let sender = env.chain.sender_addr();
+    let referrer = env.chain.addr_make("referrer");
+
+    let infusion_id = env.create_infusion()?;
+
+    let simulate = |id: u64,
+                    nfts: Vec<NFT>,
+                    funds: Vec<Coin>,
+                    referrer: Option<Addr>|
+     -> anyhow::Result<SimulateInfuseResponse> {
+        Ok(env.chain.query(
+            &QueryMsg::SimulateInfuse {
+                id,
+                sender: sender.clone(),
+                referrer,
+                bundle: vec![Bundle { nfts }],
+                funds,
+            },
+            &app.address()?,
+        )?)
+    };
+    let good_bundle = env.bundle(1).nfts;
+
+    // good bundle mints one token, splitting the mint fee
+    let res = simulate(infusion_id, good_bundle.clone(), coins(100, "ustars"), None)?;
+    assert_eq!(res.error, None);
+    assert_eq!(res.mint_count, 1);
+    assert_eq!(res.fee_msgs.len(), 2);
+    assert!(res.substitutes_consumed.is_empty());
+
+    // first validation error is returned
+    let res = simulate(infusion_id, good_bundle.clone(), coins(50, "ustars"), None)?;
+    assert_eq!(
+        res.error,
+        Some(
+            ContractError::FeeNotAccepted {
+                have: coin(50, "ustars"),
+                want: coin(100, "ustars"),
+            }
+            .to_string()
+        )
+    );
+    let res = simulate(
+        infusion_id,
+        good_bundle[..1].to_vec(),
+        coins(100, "ustars"),
+        None,
+    )?;
+    assert_eq!(
+        res.error,
+        Some(
+            ContractError::BundleCollectionNotEligilbe {
+                bun_type: 1,
+                col: env.nfts[1].to_string(),
+                wavs: false,
+                min_req: 1,
+            }
+            .to_string()
+        )
+    );
+
+    // the referrer is validated & paid its share, as when infusing
+    let res = simulate(
+        infusion_id,
+        good_bundle.clone(),
+        coins(100, "ustars"),
+        Some(sender.clone()),
+    )?;
+    assert_eq!(
+        res.error,
+        Some(ContractError::InvalidReferrer {}.to_string())
+    );
+    env.infusion.infusion_params.referral_share = Some(Decimal::percent(10));
+    let referral_id = env.create_infusion()?;
+    let res = simulate(
+        referral_id,
+        good_bundle,
+        coins(100, "ustars"),
+        Some(referrer.clone()),
+    )?;
+    assert_eq!(res.error, None);
+    assert!(res.fee_msgs.contains(
+        &BankMsg::Send {
+            to_address: referrer.to_string(),
+            amount: coins(10, "ustars"),
+        }
+        .into()
+    ));
+
+    // fungible ingredients burned are reported apart from the payment substitutes consumed
+    env.infusion.infusion_params.fungible_ingredients = vec![FungibleIngredient {
+        min_req: coin(50, "ubtsg").into(),
+        max_req: None,
+    }];
+    env.infusion.collections[1].payment_substitute = Some(coin(100, "ubtsg").into());
+    let fungible_id = env.create_infusion()?;
+    let res = simulate(
+        fungible_id,
+        env.bundle(2).nfts[..1].to_vec(),
+        vec![coin(150, "ubtsg"), coin(100, "ustars")],
+        None,
+    )?;
+    assert_eq!(res.error, None);
+    assert_eq!(res.fungibles_burned, coins(50, "ubtsg"));
+    assert_eq!(res.substitutes_consumed, coins(100, "ubtsg"));
+    assert!(res.fee_msgs.contains(
+        &BankMsg::Burn {
+            amount: coins(50, "ubtsg"),
+        }
+        .into()
+    ));
+
+    // cw20 fees are transferred with the token contract
+    let token = env.chain.addr_make("token");
+    env.infusion.infusion_params.fungible_ingredients = vec![];
+    env.infusion.collections[1].payment_substitute = None;
+    env.infusion.infusion_params.mint_fee = Some(Asset::Cw20 {
+        address: token.clone(),
+        amount: Uint128::new(100),
+    });
+    let cw20_id = env.create_infusion()?;
+    let cw20_fee = coins(100, format!("cw20:{token}"));
+    let res = simulate(cw20_id, env.bundle(1).nfts, cw20_fee.clone(), None)?;
+    assert_eq!(res.error, None);
+    assert!(res
+        .fee_msgs
+        .iter()
+        .all(|msg| matches!(msg, CosmosMsg::Wasm(_))));
+    assert!(res.fee_msgs.contains(
+        &WasmMsg::Execute {
+            contract_addr: token.to_string(),
+            msg: to_json_binary(&cw20::Cw20ExecuteMsg::Transfer {
+                recipient: env.payment_recipient.to_string(),
+                amount: Uint128::new(90),
+            })?,
+            funds: vec![],
+        }
+        .into()
+    ));
+
+    // fees held in escrow are not sent
+    app.call_as(&env.admin).execute(
+        &ExecuteMsg::UpdateConfig {
+            config: UpdatingConfig {
+                escrow_fees: Some(true),
+                ..Default::default()
+            },
+        },
+        None,
+    )?;
+    let res = simulate(cw20_id, env.bundle(1).nfts, cw20_fee, None)?;
+    assert_eq!(res.error, None);
+    assert!(res.fee_msgs.is_empty());
+    let res = simulate(infusion_id, env.bundle(1).nfts, coins(100, "ustars"), None)?;
+    assert_eq!(res.error, None);
+    assert!(res.fee_msgs.is_empty());
+
+    // simulating does not consume the bundle
+    app.execute(&env.infuse_msg(infusion_id, 1), Some(&coins(100, "ustars")))?;
+
+    Ok(())
+}
+
+#[test]
+fn test_infusion_queries() -> anyhow::Result<()> {
+    let env = InfuserSuite::<MockBech32>::setup()?;
+    let app = env.infuser;
+    let ids = |page: Vec<InfusionSummary>| page.iter().map(|i| i.id).collect::<Vec<_>>();
+    let query = |msg: QueryMsg| -> anyhow::Result<Vec<InfusionSummary>> {
+        Ok(env.chain.query(&msg, &app.address()?)?)
+    };
+
+    // legacy query returns infusions of an infused collection, no longer erroring on missing ids
+    let infused_col = Addr::unchecked(
+        app.infusion_by_id(2)?
+            .infused_collection
+            .addr
+            .expect("infusion collection not set!"),
+    );
+    let legacy = |index: u64| -> anyhow::Result<InfusionsResponse> {
+        Ok(env.chain.query(
+            &QueryMsg::Infusions {
+                addr: infused_col.clone(),
+                index,
+            },
+            &app.address()?,
+        )?)
+    };
+    assert_eq!(legacy(0)?.infusions, vec![app.infusion_by_id(2)?]);
+    assert!(legacy(3)?.infusions.is_empty());
+
+    // by owner, paginated in both directions
+    let mut all = ids(query(QueryMsg::InfusionsByOwner {
+        owner: env.admin.clone(),
+        start_after: None,
+        limit: None,
+        order: None,
+    })?);
+    assert_eq!(all, vec![1, 2, 3, 4]);
+    let first = ids(query(QueryMsg::InfusionsByOwner {
+        owner: env.admin.clone(),
+        start_after: None,
+        limit: Some(2),
+        order: None,
+    })?);
+    let rest = ids(query(QueryMsg::InfusionsByOwner {
+        owner: env.admin.clone(),
+        start_after: Some(first[1]),
+        limit: None,
+        order: None,
+    })?);
+    assert_eq!([first, rest].concat(), all);
+    let desc = ids(query(QueryMsg::InfusionsByOwner {
+        owner: env.admin.clone(),
+        start_after: None,
+        limit: None,
+        order: Some(QueryOrder::Descending),
+    })?);
+    all.reverse();
+    assert_eq!(desc, all);
+    assert!(query(QueryMsg::InfusionsByOwner {
+        owner: env.chain.sender_addr(),
+        start_after: None,
+        limit: None,
+        order: None,
+    })?
+    .is_empty());
+
+    // by infused collection
+    let infused_col = Addr::unchecked(
+        app.infusion_by_id(1)?
+            .infused_collection
+            .addr
+            .expect("infusion collection not set!"),
+    );
+    let res = query(QueryMsg::InfusionsByCollection {
+        addr: infused_col,
+        start_after: None,
+        limit: None,
+        order: None,
+    })?;
+    assert_eq!(ids(res), vec![1]);
+
+    // by enabled status
+    env.chain.call_as(&env.admin).execute(
+        &ExecuteMsg::EndInfusion { id: 2 },
+        &[],
+        &app.address()?,
+    )?;
+    let all_infusions = |enabled: Option<bool>| {
+        query(QueryMsg::AllInfusions {
+            enabled,
+            start_after: None,
+            limit: None,
+            order: None,
+        })
+    };
+    assert_eq!(all_infusions(None)?.len(), 4);
+    assert_eq!(all_infusions(Some(true))?.len(), 3);
+    assert_eq!(ids(all_infusions(Some(false))?), vec![2]);
+
+    Ok(())
+}
+
+#[test]
+fn test_infusions_for_collection() -> anyhow::Result<()> {
+    let env = InfuserSuite::<MockBech32>::setup()?;
+    let app = env.infuser;
+    let for_collection = |addr: &Addr, start_after: Option<u64>| -> anyhow::Result<Vec<u64>> {
+        let res: Vec<InfusionSummary> = env.chain.query(
+            &QueryMsg::InfusionsForCollection {
+                addr: addr.clone(),
+                start_after,
+                limit: None,
+            },
+            &app.address()?,
+        )?;
+        Ok(res.iter().map(|i| i.id).collect())
+    };
+    assert_eq!(for_collection(&env.nfts[1], None)?, vec![2]);
+
+    // make nfts[1] eligible for infusion 1 as well
+    let mut infusion = app.infusion_by_id(1)?;
+    let mut col = infusion.collections[0].clone();
+    col.addr = env.nfts[1].clone();
+    app.call_as(&env.admin)
+        .update_infusions_eligible_collections_or_bundle_type(
+            infusion.infusion_params.bundle_type.clone(),
+            1,
+            vec![col],
+            vec![],
+        )?;
+    assert_eq!(for_collection(&env.nfts[1], None)?, vec![1, 2]);
+    assert_eq!(for_collection(&env.nfts[1], Some(1))?, vec![2]);
+
+    // batch variant
+    let res: Vec<CollectionInfusions> = env.chain.query(
+        &QueryMsg::InfusionsForCollections {
+            addrs: vec![env.nfts[0].clone(), env.nfts[1].clone()],
+            limit: None,
+        },
+        &app.address()?,
+    )?;
+    assert_eq!(res.len(), 2);
+    assert_eq!(res[0].addr, env.nfts[0]);
+    assert_eq!(res[0].infusions.len(), 1);
+    assert_eq!(res[1].infusions.len(), 2);
+
+    // at most 10 collections are queried at once
+    let batch = |count: usize| -> anyhow::Result<Vec<CollectionInfusions>> {
+        Ok(env.chain.query(
+            &QueryMsg::InfusionsForCollections {
+                addrs: vec![env.nfts[0].clone(); count],
+                limit: None,
+            },
+            &app.address()?,
+        )?)
+    };
+    assert_eq!(batch(10)?.len(), 10);
+    assert!(batch(11).is_err());
+
+    // removed collections are pruned
+    infusion = app.infusion_by_id(1)?;
+    app.call_as(&env.admin)
+        .update_infusions_eligible_collections_or_bundle_type(
+            infusion.infusion_params.bundle_type,
+            1,
+            vec![],
+            vec![env.nfts[1].clone()],
+        )?;
+    assert_eq!(for_collection(&env.nfts[1], None)?, vec![2]);
+    assert_eq!(for_collection(&env.nfts[0], None)?, vec![1]);
+
+    Ok(())
+}
+
+#[test]
+fn test_infusion_stats() -> anyhow::Result<()> {
+    let env = InfuserSuite::<MockBech32>::setup()?;
+    let app = env.infuser;
+    let bundle = |a: u64, b: u64| {
+        vec![Bundle {
+            nfts: vec![
+                NFT {
+                    addr: env.nfts[0].clone(),
+                    token_id: a,
+                },
+                NFT {
+                    addr: env.nfts[0].clone(),
+                    token_id: b,
+                },
+            ],
+        }]
+    };
+
+    let stats = app.infusion_stats(1)?;
+    assert_eq!(stats.minted, 0);
+    assert_eq!(stats.remaining, stats.total_supply);
+    assert_eq!(stats.infuse_count, 0);
+
+    app.infuse(bundle(1, 3), 1, None, None)?;
+    app.infuse(bundle(5, 7), 1, None, None)?;
+    app.call_as(&env.admin)
+        .infuse(bundle(11, 12), 1, None, None)?;
+
+    let stats = app.infusion_stats(1)?;
+    assert_eq!(stats.minted, 3);
+    assert_eq!(stats.remaining, stats.total_supply - 3);
+    assert_eq!(stats.infuse_count, 3);
+    assert_eq!(stats.unique_burners, 2);
+    assert_eq!(stats.burned.len(), 1);
+    assert_eq!(stats.burned[0].addr, env.nfts[0]);
+    assert_eq!(stats.burned[0].count, 6);
+    assert!(stats.fees.is_empty());
+
+    // other infusions are unaffected
+    assert_eq!(app.infusion_stats(2)?.infuse_count, 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_shuffle() -> anyhow::Result<()> {
+    let env = InfuserSuite::<MockBech32>::setup()?;
+    let app = env.infuser;
+
+    // reordering the mintable tokens is reported as an infusion update
+    let res = app.call_as(&env.admin).shuffle(1)?;
+    assert_eq!(
+        res.event_attr_value("wasm-infusion-update", "field")?,
+        "token_order"
+    );
+    assert_eq!(
+        res.event_attr_value("wasm-infusion-update", "value")?,
+        app.infusion_stats(1)?.remaining.to_string()
+    );
+    assert_eq!(
+        res.event_attr_value("wasm-infusion-update", "sender")?,
+        env.admin.to_string()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_leaderboard() -> anyhow::Result<()> {
+    let env = InfuserSuite::<MockBech32>::setup()?;
+    let app = env.infuser;
+    let sender = env.chain.sender_addr();
+    let bundle = |a: u64, b: u64| {
+        vec![Bundle {
+            nfts: vec![
+                NFT {
+                    addr: env.nfts[0].clone(),
+                    token_id: a,
+                },
+                NFT {
+                    addr: env.nfts[0].clone(),
+                    token_id: b,
+                },
+            ],
+        }]
+    };
+
+    app.call_as(&env.admin)
+        .infuse(bundle(11, 12), 1, None, None)?;
+    app.infuse(bundle(1, 3), 1, None, None)?;
+    app.infuse(bundle(5, 7), 1, None, None)?;
+
+    let stats = app.burner_stats(sender.clone(), 1)?;
+    assert_eq!(stats.burns, 4);
+    assert_eq!(stats.mints, 2);
+    assert_eq!(app.burner_stats(sender.clone(), 2)?.burns, 0);
+
+    let board = app.leaderboard(1, None)?;
+    assert_eq!(board.len(), 2);
+    assert_eq!(board[0].addr, sender);
+    assert_eq!(board[0].burns, 4);
+    assert_eq!(board[1].addr, env.admin);
+    assert_eq!(board[1].burns, 2);
+    assert_eq!(board[1].mints, 1);
+    assert_eq!(app.leaderboard(1, Some(1))?.len(), 1);
+    assert!(app.leaderboard(2, None)?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_infusion_events() -> anyhow::Result<()> {
+    let env = InfuserSuite::<MockBech32>::setup()?;
+    let app = env.infuser;
+
+    let res = app.infuse(
+        vec![Bundle {
+            nfts: vec![
+                NFT {
+                    addr: env.nfts[0].clone(),
+                    token_id: 1,
+                },
+                NFT {
+                    addr: env.nfts[0].clone(),
+                    token_id: 3,
+                },
+            ],
+        }],
+        1,
+        None,
+        None,
+    )?;
+    assert_eq!(
+        res.event_attr_values("wasm-infusion-burn", "token_id"),
+        vec!["1", "3"]
+    );
+    assert_eq!(
+        res.event_attr_values("wasm-infusion-burn", "disposition"),
+        vec!["burn", "burn"]
+    );
+    assert_eq!(res.event_attr_value("wasm-infusion-infuse", "minted")?, "1");
+    assert_eq!(
+        res.event_attr_values("wasm-infusion-mint", "infusion_id"),
+        vec!["1"]
+    );
+    // no fees for this infusion
+    assert!(res
+        .event_attr_values("wasm-infusion-fee-split", "payment")
+        .is_empty());
+
+    let res = app
+        .call_as(&env.admin)
+        .update_infusion_base_uri("ipfs://new".to_string(), 1)?;
+    assert_eq!(
+        res.event_attr_value("wasm-infusion-update", "field")?,
+        "base_uri"
+    );
+    assert_eq!(
+        res.event_attr_value("wasm-infusion-update", "value")?,
+        "ipfs://new"
+    );
+
+    let res = app.call_as(&env.admin).end_infusion(1)?;
+    assert_eq!(
+        res.event_attr_value("wasm-infusion-update", "field")?,
+        "enabled"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_refund_unspent_funds() -> anyhow::Result<()> {
+    let env = InfuserSuite::<MockBech32>::setup_fee_suite(BundleType::AllOf {}, vec![], false)?;
+    let app = env.infuser.clone();
+    env.chain.add_balance(&env.admin, coins(50, "ubtsg"))?;
+
+    let infusion_id = env.create_infusion()?;
+
+    // mint fee is required, even if its denom is not sent
+    for funds in [vec![], coins(50, "ubtsg")] {
+        assert_eq!(
+            app.call_as(&env.admin)
+                .execute(&env.infuse_msg(infusion_id, 11), Some(&funds))
+                .unwrap_err()
+                .downcast::<ContractError>()?
+                .to_string(),
+            ContractError::FeeNotAccepted {
+                have: coin(0, "ustars"),
+                want: coin(100, "ustars"),
+            }
+            .to_string()
+        );
+    }
+
+    // overpayment & unexpected denoms are refunded
+    let stars_before = env.chain.query_balance(&env.admin, "ustars")?;
+    let btsg_before = env.chain.query_balance(&env.admin, "ubtsg")?;
+    let recipient_before = env.chain.query_balance(&env.payment_recipient, "ustars")?;
+    app.call_as(&env.admin).execute(
+        &env.infuse_msg(infusion_id, 11),
+        Some(&[coin(250, "ustars"), coin(50, "ubtsg")]),
+    )?;
+    // admin is also the contract owner, receiving 10% of the fee back
+    assert_eq!(
+        env.chain.query_balance(&env.admin, "ustars")?,
+        stars_before - Uint128::new(90)
+    );
+    assert_eq!(env.chain.query_balance(&env.admin, "ubtsg")?, btsg_before);
+    assert_eq!(
+        env.chain.query_balance(&env.payment_recipient, "ustars")?,
+        recipient_before + Uint128::new(90)
+    );
+
+    // nothing is left in the infuser
+    assert!(env
+        .chain
+        .query_balance(&app.address()?, "ustars")?
+        .is_zero());
+    assert!(env.chain.query_balance(&app.address()?, "ubtsg")?.is_zero());
+
+    Ok(())
+}
+
+#[test]
+fn test_fee_escrow() -> anyhow::Result<()> {
+    let env = InfuserSuite::<MockBech32>::setup_fee_suite(BundleType::AllOf {}, vec![], false)?;
+    let app = env.infuser.clone();
+    app.call_as(&env.admin).execute(
+        &ExecuteMsg::UpdateConfig {
+            config: UpdatingConfig {
+                escrow_fees: Some(true),
+                ..Default::default()
+            },
+        },
+        None,
+    )?;
+    let fee_balance = |addr: &Addr| -> anyhow::Result<Vec<Asset>> {
+        Ok(env.chain.query(
+            &QueryMsg::FeeBalance { addr: addr.clone() },
+            &app.address()?,
+        )?)
+    };
+
+    // fees are only ever credited to validated recipients
+    let mut invalid = env.infusion.clone();
+    invalid.payment_recipient = Some(Addr::unchecked("not-an-address"));
+    assert!(app
+        .execute(
+            &ExecuteMsg::CreateInfusion {
+                infusions: vec![invalid],
+            },
+            Some(&[coin(500, "ustars")]),
+        )
+        .is_err());
+
+    let infusion_id = env.create_infusion()?;
+
+    // fees are credited rather than sent
+    let recipient_before = env.chain.query_balance(&env.payment_recipient, "ustars")?;
+    app.call_as(&env.admin).execute(
+        &env.infuse_msg(infusion_id, 11),
+        Some(&[coin(100, "ustars")]),
+    )?;
+    assert_eq!(
+        env.chain.query_balance(&env.payment_recipient, "ustars")?,
+        recipient_before
+    );
+    assert_eq!(
+        fee_balance(&env.payment_recipient)?,
+        vec![coin(90, "ustars").into()]
+    );
+    // creation fee & owner share of the mint fee
+    assert_eq!(fee_balance(&env.admin)?, vec![coin(510, "ustars").into()]);
+    let escrowed: Vec<Asset> = env
+        .chain
+        .query(&QueryMsg::EscrowedFees {}, &app.address()?)?;
+    assert_eq!(escrowed, vec![coin(600, "ustars").into()]);
+
+    // recipients withdraw their own balance
+    app.call_as(&env.payment_recipient)
+        .execute(&ExecuteMsg::ClaimFees { denoms: None }, None)?;
+    assert_eq!(
+        env.chain.query_balance(&env.payment_recipient, "ustars")?,
+        recipient_before + Uint128::new(90)
+    );
+    assert!(fee_balance(&env.payment_recipient)?.is_empty());
+    assert_eq!(
+        app.call_as(&env.payment_recipient)
+            .execute(&ExecuteMsg::ClaimFees { denoms: None }, None)
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string(),
+        ContractError::NothingToClaim.to_string()
+    );
+    let escrowed: Vec<Asset> = env
+        .chain
+        .query(&QueryMsg::EscrowedFees {}, &app.address()?)?;
+    assert_eq!(escrowed, vec![coin(510, "ustars").into()]);
+    assert_eq!(
+        env.chain.query_balance(&app.address()?, "ustars")?,
+        Uint128::new(510)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_cw20_fees() -> anyhow::Result<()> {
+    let mut env = InfuserSuite::<MockBech32>::setup_fee_suite(BundleType::AllOf {}, vec![], false)?;
+    let app = env.infuser.clone();
+    let cw20_code_id = env
+        .chain
+        .upload_custom("cw20", cw20_contract())?
+        .uploaded_code_id()?;
+    let instantiate_token = |symbol: &str| -> anyhow::Result<Addr> {
+        Ok(env
+            .chain
+            .instantiate(
+                cw20_code_id,
+                &cw20_base::msg::InstantiateMsg {
+                    name: "infusion token".to_string(),
+                    symbol: symbol.to_string(),
+                    decimals: 6,
+                    initial_balances: vec![cw20::Cw20Coin {
+                        address: env.admin.to_string(),
+                        amount: Uint128::new(1000),
+                    }],
+                    mint: None,
+                    marketing: None,
+                },
+                Some("cw20"),
+                None,
+                &[],
+            )?
+            .instantiated_contract_address()?)
+    };
+    let token = instantiate_token("INFUSE")?;
+    let cw20_balance = |addr: &Addr| -> anyhow::Result<Uint128> {
+        let res: cw20::BalanceResponse = env.chain.query(
+            &cw20::Cw20QueryMsg::Balance {
+                address: addr.to_string(),
+            },
+            &token,
+        )?;
+        Ok(res.balance)
+    };
+
+    // native denoms cannot impersonate cw20 tokens
+    let denom = format!("cw20:{token}");
+    env.infusion.infusion_params.mint_fee = Some(coin(100, &denom).into());
+    assert_eq!(
+        app.execute(
+            &ExecuteMsg::CreateInfusion {
+                infusions: vec![env.infusion.clone()],
+            },
+            Some(&[coin(500, "ustars")]),
+        )
+        .unwrap_err()
+        .downcast::<ContractError>()?
+        .to_string(),
+        ContractError::Std(StdError::generic_err(format!(
+            "Native denom {denom} must not use the cw20: prefix"
+        )))
+        .to_string()
+    );
+
+    env.infusion.infusion_params.mint_fee = Some(Asset::Cw20 {
+        address: token.clone(),
+        amount: Uint128::new(100),
+    });
+    let infusion_id = env.create_infusion()?;
+
+    // mint fee is paid by sending cw20 tokens with an infuse payload, with the excess refunded
+    env.chain.call_as(&env.admin).execute(
+        &cw20::Cw20ExecuteMsg::Send {
+            contract: app.address()?.to_string(),
+            amount: Uint128::new(150),
+            msg: to_json_binary(&ReceiveCw20Msg::Infuse {
+                id: infusion_id,
+                bundle: vec![env.bundle(11)],
+                recipient: None,
+                referrer: None,
+            })?,
+        },
+        &[],
+        &token,
+    )?;
+    assert_eq!(cw20_balance(&env.payment_recipient)?, Uint128::new(90));
+    // admin is also the contract owner, receiving 10% of the fee back
+    assert_eq!(cw20_balance(&env.admin)?, Uint128::new(1000 - 90));
+    assert!(cw20_balance(&app.address()?)?.is_zero());
+
+    // infusing without the cw20 fee errors
+    assert_eq!(
+        app.call_as(&env.admin)
+            .execute(&env.infuse_msg(infusion_id, 12), None)
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string(),
+        ContractError::FeeNotAccepted {
+            have: coin(0, format!("cw20:{token}")),
+            want: coin(100, format!("cw20:{token}")),
+        }
+        .to_string()
+    );
+
+    // tokens the infusion is not paid with are rejected
+    let fake = instantiate_token("FAKE")?;
+    let infuse_payload = to_json_binary(&ReceiveCw20Msg::Infuse {
+        id: infusion_id,
+        bundle: vec![env.bundle(12)],
+        recipient: None,
+        referrer: None,
+    })?;
+    assert_eq!(
+        env.chain
+            .call_as(&env.admin)
+            .execute(
+                &cw20::Cw20ExecuteMsg::Send {
+                    contract: app.address()?.to_string(),
+                    amount: Uint128::new(100),
+                    msg: infuse_payload.clone(),
+                },
+                &[],
+                &fake,
+            )
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string(),
+        ContractError::Cw20NotAccepted {
+            token: fake.to_string()
+        }
+        .to_string()
+    );
+    let create_payload = to_json_binary(&ReceiveCw20Msg::CreateInfusion {
+        infusions: vec![env.infusion.clone()],
+    })?;
+    assert_eq!(
+        env.chain
+            .call_as(&env.admin)
+            .execute(
+                &cw20::Cw20ExecuteMsg::Send {
+                    contract: app.address()?.to_string(),
+                    amount: Uint128::new(500),
+                    msg: create_payload,
+                },
+                &[],
+                &token,
+            )
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string(),
+        ContractError::Cw20NotAccepted {
+            token: token.to_string()
+        }
+        .to_string()
+    );
+
+    // receive hooks cannot be spoofed to spend the approved nfts of another sender
+    let attacker = env
+        .chain
+        .addr_make_with_balance("attacker", coins(100, "ustars"))?;
+    let spoofed = ExecuteMsg::Receive(cw20::Cw20ReceiveMsg {
+        sender: env.admin.to_string(),
+        amount: Uint128::new(100),
+        msg: infuse_payload,
+    });
+    assert_eq!(
+        app.call_as(&attacker)
+            .execute(&spoofed, Some(&[coin(100, "ustars")]))
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string(),
+        ContractError::Cw20WithNativeFunds {}.to_string()
+    );
+    assert_eq!(
+        app.call_as(&attacker)
+            .execute(&spoofed, None)
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string(),
+        ContractError::Cw20NotAccepted {
+            token: attacker.to_string()
+        }
+        .to_string()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_fungible_ingredients() -> anyhow::Result<()> {
+    let mut env = InfuserSuite::<MockBech32>::setup_fee_suite(BundleType::AllOf {}, vec![], false)?;
+    let app = env.infuser.clone();
+    env.chain.add_balance(&env.admin, coins(200, "ubtsg"))?;
+
+    // the minimum burned must be non-zero & no greater than the maximum
+    for (min_req, max_req) in [(0, None), (50, Some(Uint128::new(49)))] {
+        env.infusion.infusion_params.fungible_ingredients = vec![FungibleIngredient {
+            min_req: coin(min_req, "ubtsg").into(),
+            max_req,
+        }];
+        assert_eq!(
+            app.execute(
+                &ExecuteMsg::CreateInfusion {
+                    infusions: vec![env.infusion.clone()],
+                },
+                Some(&[coin(500, "ustars")]),
+            )
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string(),
+            ContractError::InvalidFungibleIngredient {
+                denom: "ubtsg".to_string()
+            }
+            .to_string()
+        );
+    }
+
+    env.infusion.infusion_params.fungible_ingredients[0].max_req = None;
+    let infusion_id = env.create_infusion()?;
+    let infuse = ExecuteMsg::Infuse {
+        id: infusion_id,
+        bundle: vec![env.bundle(11), env.bundle(12)],
+        recipient: None,
+        referrer: None,
+    };
+
+    // bundle is not satisfied without the fungible amount
+    assert_eq!(
+        app.call_as(&env.admin)
+            .execute(&infuse, Some(&[coin(100, "ustars"), coin(30, "ubtsg")]))
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string(),
+        ContractError::FungibleIngredientNotProvided {
+            have: coin(30, "ubtsg"),
+            want: coin(50, "ubtsg"),
+        }
+        .to_string()
+    );
+
+    // the second bundle is not satisfied by what is left after the first
+    assert_eq!(
+        app.call_as(&env.admin)
+            .execute(&infuse, Some(&[coin(100, "ustars"), coin(90, "ubtsg")]))
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string(),
+        ContractError::FungibleIngredientNotProvided {
+            have: coin(40, "ubtsg"),
+            want: coin(50, "ubtsg"),
+        }
+        .to_string()
+    );
+
+    // each bundle burns exactly the ingredient amount, with the excess refunded
+    app.call_as(&env.admin)
+        .execute(&infuse, Some(&[coin(100, "ustars"), coin(110, "ubtsg")]))?;
+    assert_eq!(
+        env.chain.query_balance(&env.admin, "ubtsg")?,
+        Uint128::new(100)
+    );
+    // burned ingredients are not counted as fees collected
+    assert_eq!(
+        app.infusion_stats(infusion_id)?.fees,
+        vec![coin(100, "ustars")]
+    );
+    assert!(env.chain.query_balance(&app.address()?, "ubtsg")?.is_zero());
+
+    // up to the maximum is burned from the funds sent
+    env.infusion.infusion_params.fungible_ingredients[0].max_req = Some(Uint128::new(80));
+    let infusion_id = env.create_infusion()?;
+    app.call_as(&env.admin).execute(
+        &env.infuse_msg(infusion_id, 13),
+        Some(&[coin(100, "ustars"), coin(100, "ubtsg")]),
+    )?;
+    assert_eq!(
+        env.chain.query_balance(&env.admin, "ubtsg")?,
+        Uint128::new(20)
+    );
+
+    // satisfied ingredients count as an eligible ingredient of AnyOf bundles, & are optional
+    env.chain.add_balance(&env.admin, coins(100, "ubtsg"))?;
+    env.infusion.infusion_params.bundle_type = BundleType::AnyOf {
+        addrs: vec![env.nfts[0].clone(), env.nfts[1].clone()],
+    };
+    let infusion_id = env.create_infusion()?;
+    let infuse_any = |token_id: u64| ExecuteMsg::Infuse {
+        id: infusion_id,
+        bundle: vec![Bundle {
+            nfts: vec![NFT {
+                addr: env.nfts[0].clone(),
+                token_id,
+            }],
+        }],
+        recipient: None,
+        referrer: None,
+    };
+    let res = app.call_as(&env.admin).execute(
+        &infuse_any(14),
+        Some(&[coin(100, "ustars"), coin(50, "ubtsg")]),
+    )?;
+    assert_eq!(res.event_attr_value("wasm-infusion-infuse", "minted")?, "2");
+    let res = app
+        .call_as(&env.admin)
+        .execute(&infuse_any(15), Some(&[coin(100, "ustars")]))?;
+    assert_eq!(res.event_attr_value("wasm-infusion-infuse", "minted")?, "1");
+
+    // payment substitutes are counted as fees collected, apart from the burned ingredients
+    env.chain.add_balance(&env.admin, coins(150, "ubtsg"))?;
+    env.infusion.infusion_params.bundle_type = BundleType::AllOf {};
+    env.infusion.infusion_params.fungible_ingredients[0].max_req = None;
+    env.infusion.collections[1].payment_substitute = Some(coin(100, "ubtsg").into());
+    let infusion_id = env.create_infusion()?;
+    app.call_as(&env.admin).execute(
+        &ExecuteMsg::Infuse {
+            id: infusion_id,
+            bundle: vec![Bundle {
+                nfts: env.bundle(16).nfts[..1].to_vec(),
+            }],
+            recipient: None,
+            referrer: None,
+        },
+        Some(&[coin(100, "ustars"), coin(150, "ubtsg")]),
+    )?;
+    assert_eq!(
+        app.infusion_stats(infusion_id)?.fees,
+        vec![coin(100, "ustars"), coin(100, "ubtsg")]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dutch_auction_pricing() -> anyhow::Result<()> {
+    let mut env = InfuserSuite::<MockBech32>::setup_fee_suite(BundleType::AllOf {}, vec![], false)?;
+    let app = env.infuser.clone();
+    let start_time = env.chain.block_info()?.time;
+
+    // the start price must not be less than the mint fee floor
+    env.infusion.infusion_params.pricing = Some(PricingStrategy::DutchAuction {
+        start_price: Uint128::new(50),
+        start_time,
+        duration: 900,
+        decay: Decay::Linear,
+    });
+    assert_eq!(
+        app.execute(
+            &ExecuteMsg::CreateInfusion {
+                infusions: vec![env.infusion.clone()],
+            },
+            Some(&[coin(500, "ustars")]),
+        )
+        .unwrap_err()
+        .downcast::<ContractError>()?
+        .to_string(),
+        ContractError::InvalidPricingStrategy {
+            reason: "start price is less than the mint fee".to_string()
+        }
+        .to_string()
+    );
+
+    env.infusion.infusion_params.pricing = Some(PricingStrategy::DutchAuction {
+        start_price: Uint128::new(1000),
+        start_time,
+        duration: 900,
+        decay: Decay::Linear,
+    });
+    let infusion_id = env.create_infusion()?;
+    let price = |env: &InfuserSuite<MockBech32>| -> anyhow::Result<Option<Asset>> {
+        Ok(env.chain.query(
+            &QueryMsg::CurrentPrice { id: infusion_id },
+            &env.infuser.address()?,
+        )?)
+    };
+    assert_eq!(price(&env)?, Some(coin(1000, "ustars").into()));
+
+    // halfway through, the price has decayed halfway to the floor
+    env.chain.wait_seconds(450)?;
+    assert_eq!(price(&env)?, Some(coin(550, "ustars").into()));
+
+    // paying the previous floor is not enough
+    let infuse = env.infuse_msg(infusion_id, 11);
+    assert!(app
+        .call_as(&env.admin)
+        .execute(&infuse, Some(&[coin(100, "ustars")]))
+        .is_err());
+
+    // once the auction ends, the mint fee is the floor
+    env.chain.wait_seconds(450)?;
+    assert_eq!(price(&env)?, Some(coin(100, "ustars").into()));
+    app.call_as(&env.admin)
+        .execute(&infuse, Some(&[coin(100, "ustars")]))?;
+    Ok(())
+}
+
+#[test]
+fn test_bonding_curve_pricing() -> anyhow::Result<()> {
+    let mut env = InfuserSuite::<MockBech32>::setup_fee_suite(BundleType::AllOf {}, vec![], false)?;
+    let app = env.infuser.clone();
+
+    // a zero exponent would hold the price at the max from the first mint
+    env.infusion.infusion_params.pricing = Some(PricingStrategy::BondingCurve {
+        max_price: Uint128::new(1000),
+        exponent: 0,
+    });
+    assert_eq!(
+        app.execute(
+            &ExecuteMsg::CreateInfusion {
+                infusions: vec![env.infusion.clone()],
+            },
+            Some(&[coin(500, "ustars")]),
+        )
+        .unwrap_err()
+        .downcast::<ContractError>()?
+        .to_string(),
+        ContractError::InvalidPricingStrategy {
+            reason: "exponent must be non-zero".to_string()
+        }
+        .to_string()
+    );
+
+    // the price starts at the floor
+    env.infusion.infusion_params.pricing = Some(PricingStrategy::BondingCurve {
+        max_price: Uint128::new(1000),
+        exponent: 1,
+    });
+    let infusion_id = env.create_infusion()?;
+    let price: Option<Asset> = env
+        .chain
+        .query(&QueryMsg::CurrentPrice { id: infusion_id }, &app.address()?)?;
+    assert_eq!(price, Some(coin(100, "ustars").into()));
+    Ok(())
+}
+
+#[test]
+fn test_oracle_pricing() -> anyhow::Result<()> {
+    let mut env = InfuserSuite::<MockBech32>::setup_fee_suite(BundleType::AllOf {}, vec![], false)?;
+    let app = env.infuser.clone();
+    let oracle_code_id = env
+        .chain
+        .upload_custom("mock-oracle", mock_oracle_contract())?
+        .uploaded_code_id()?;
+    let oracle = env
+        .chain
+        .instantiate(oracle_code_id, &Empty {}, Some("oracle"), None, &[])?
+        .instantiated_contract_address()?;
+    let set_price = |price: &str, updated_at: Option<Timestamp>| -> anyhow::Result<()> {
+        env.chain.execute(
+            &MockOracleExecuteMsg::SetPrice {
+                denom: "ustars".to_string(),
+                price: Decimal::from_str(price)?,
+                updated_at,
+            },
+            &[],
+            &oracle,
+        )?;
+        Ok(())
+    };
+    // 1ustars is worth 0.5, so a target value of 100 is worth 200ustars
+    set_price("0.5", None)?;
+
+    env.infusion.infusion_params.pricing = Some(PricingStrategy::Oracle(OraclePricing {
+        oracle: oracle.clone(),
+        target_value: Uint128::new(100),
+        max_staleness: 60,
+    }));
+    let infusion_id = env.create_infusion()?;
+    let price = |env: &InfuserSuite<MockBech32>| -> anyhow::Result<Option<Asset>> {
+        Ok(env.chain.query(
+            &QueryMsg::CurrentPrice { id: infusion_id },
+            &env.infuser.address()?,
+        )?)
+    };
+    assert_eq!(price(&env)?, Some(coin(200, "ustars").into()));
+
+    // payments below the oracle price are rejected
+    assert_eq!(
+        app.call_as(&env.admin)
+            .execute(
+                &env.infuse_msg(infusion_id, 11),
+                Some(&[coin(199, "ustars")])
+            )
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string(),
+        ContractError::FeeNotAccepted {
+            have: coin(199, "ustars"),
+            want: coin(200, "ustars"),
+        }
+        .to_string()
+    );
+
+    // the oracle price is charged, with the amount sent above it refunded.
+    // admin is refunded the 10% owner fee as the contract owner
+    let before = env.chain.query_balance(&env.admin, "ustars")?;
+    app.call_as(&env.admin).execute(
+        &env.infuse_msg(infusion_id, 11),
+        Some(&[coin(250, "ustars")]),
+    )?;
+    assert_eq!(
+        env.chain.query_balance(&env.admin, "ustars")?,
+        before - Uint128::new(200 - 20)
+    );
+
+    // prices updated after the current block are rejected
+    set_price("0.5", Some(env.chain.block_info()?.time.plus_seconds(10)))?;
+    assert!(price(&env).is_err());
+    set_price("0.5", None)?;
+
+    // stale prices are rejected
+    env.chain.wait_seconds(61)?;
+    assert!(price(&env).is_err());
+    assert!(app
+        .call_as(&env.admin)
+        .execute(
+            &env.infuse_msg(infusion_id, 12),
+            Some(&[coin(200, "ustars")])
+        )
+        .is_err());
+
+    // the mint fee remains the floor price
+    set_price("2", None)?;
+    assert_eq!(price(&env)?, Some(coin(100, "ustars").into()));
+    Ok(())
+}
+
+#[test]
+fn test_holder_fee_discounts() -> anyhow::Result<()> {
+    let mut env = InfuserSuite::<MockBech32>::setup_fee_suite(BundleType::AllOf {}, vec![], false)?;
+    let app = env.infuser.clone();
+    let rule = |min_held: u32, discount: &str| FeeDiscount {
+        collection: env.nfts[2].clone(),
+        min_held,
+        discount: Decimal::from_str(discount).unwrap(),
+    };
+
+    // discounts must be within 0-100%
+    env.infusion.infusion_params.fee_discounts = vec![rule(10, "1.5")];
+    assert_eq!(
+        app.execute(
+            &ExecuteMsg::CreateInfusion {
+                infusions: vec![env.infusion.clone()],
+            },
+            Some(&[coin(500, "ustars")]),
+        )
+        .unwrap_err()
+        .downcast::<ContractError>()?
+        .to_string(),
+        ContractError::InvalidFeeDiscount {
+            collection: env.nfts[2].to_string()
+        }
+        .to_string()
+    );
+
+    // admin holds 10 tokens of the collection, so only the 50% discount applies;
+    // a collection that cannot answer cw721 queries grants nothing instead of failing the infuse
+    env.infusion.infusion_params.fee_discounts = vec![
+        rule(10, "0.5"),
+        rule(11, "1"),
+        FeeDiscount {
+            collection: app.address()?,
+            min_held: 1,
+            discount: Decimal::percent(90),
+        },
+    ];
+    let infusion_id = env.create_infusion()?;
+
+    let before = env.chain.query_balance(&env.admin, "ustars")?;
+    app.call_as(&env.admin).execute(
+        &env.infuse_msg(infusion_id, 11),
+        Some(&[coin(100, "ustars")]),
+    )?;
+    // 50 paid, of which 5 returns to admin as the contract owner share
+    assert_eq!(
+        env.chain.query_balance(&env.admin, "ustars")?,
+        before - Uint128::new(50 - 5)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_referral_rewards() -> anyhow::Result<()> {
+    let mut env = InfuserSuite::<MockBech32>::setup_fee_suite(BundleType::AllOf {}, vec![], false)?;
+    let app = env.infuser.clone();
+    let referrer = env.chain.addr_make("referrer");
+
+    env.infusion.infusion_params.referral_share = Some(Decimal::percent(20));
+    let infusion_id = env.create_infusion()?;
+    let infuse = |referrer: &Addr| ExecuteMsg::Infuse {
+        id: infusion_id,
+        bundle: vec![env.bundle(11)],
+        recipient: None,
+        referrer: Some(referrer.to_string()),
+    };
+
+    // sender cannot refer themselves
+    assert_eq!(
+        app.call_as(&env.admin)
+            .execute(&infuse(&env.admin), Some(&[coin(100, "ustars")]))
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string(),
+        ContractError::InvalidReferrer {}.to_string()
+    );
+
+    let before = env.chain.query_balance(&env.payment_recipient, "ustars")?;
+    app.call_as(&env.admin)
+        .execute(&infuse(&referrer), Some(&[coin(100, "ustars")]))?;
+    // 20 to the referrer, with the remaining 80 split as usual
+    assert_eq!(
+        env.chain.query_balance(&referrer, "ustars")?,
+        Uint128::new(20)
+    );
+    assert_eq!(
+        env.chain.query_balance(&env.payment_recipient, "ustars")?,
+        before + Uint128::new(72)
+    );
+    let stats: ReferralStatsResponse = env.chain.query(
+        &QueryMsg::ReferralStats {
+            addr: referrer.clone(),
+        },
+        &app.address()?,
+    )?;
+    assert_eq!(stats.referrals, 1);
+    assert_eq!(stats.earned, vec![coin(20, "ustars").into()]);
+    Ok(())
+}
+
+#[test]
+fn test_source_royalties() -> anyhow::Result<()> {
+    let mut env = InfuserSuite::<MockBech32>::setup_fee_suite(BundleType::AllOf {}, vec![], false)?;
+    let app = env.infuser.clone();
+    let creator = env.chain.addr_make("source-creator");
+
+    // royalty & referral shares cannot exceed the mint fee
+    env.infusion.collections[0].source_royalty = Some(Decimal::percent(60));
+    env.infusion.collections[1].source_royalty = Some(Decimal::percent(60));
+    assert_eq!(
+        app.execute(
+            &ExecuteMsg::CreateInfusion {
+                infusions: vec![env.infusion.clone()],
+            },
+            Some(&[coin(500, "ustars")]),
+        )
+        .unwrap_err()
+        .downcast::<ContractError>()?
+        .to_string(),
+        ContractError::FeeSharesExceeded {
+            total: Decimal::percent(120)
+        }
+        .to_string()
+    );
+
+    env.infusion.collections[0].source_royalty = Some(Decimal::percent(10));
+    env.infusion.collections[1].source_royalty = None;
+    let infusion_id = env.create_infusion()?;
+
+    // without a registered recipient or cw2981 support, the royalty is skipped
+    let before = env.chain.query_balance(&env.payment_recipient, "ustars")?;
+    let res = app.call_as(&env.admin).execute(
+        &env.infuse_msg(infusion_id, 12),
+        Some(&[coin(100, "ustars")]),
+    )?;
+    assert_eq!(
+        res.event_attr_value("wasm-infusion-royalty-skipped", "collection")?,
+        env.nfts[0].to_string()
+    );
+    assert_eq!(
+        env.chain.query_balance(&env.payment_recipient, "ustars")?,
+        before + Uint128::new(90)
+    );
+
+    // only the collection creator may register its royalty recipient
+    let register = ExecuteMsg::RegisterRoyaltyRecipient {
+        collection: env.nfts[0].to_string(),
+        recipient: Some(creator.to_string()),
+    };
+    assert_eq!(
+        app.call_as(&env.admin)
+            .execute(&register, None)
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string(),
+        ContractError::Unauthorized.to_string()
+    );
+    app.execute(&register, None)?;
+    let registered: Option<Addr> = env.chain.query(
+        &QueryMsg::RoyaltyRecipient {
+            collection: env.nfts[0].clone(),
+        },
+        &app.address()?,
+    )?;
+    assert_eq!(registered, Some(creator.clone()));
+
+    let before = env.chain.query_balance(&env.payment_recipient, "ustars")?;
+    app.call_as(&env.admin).execute(
+        &env.infuse_msg(infusion_id, 11),
+        Some(&[coin(100, "ustars")]),
+    )?;
+    // 10 to the source creator, with the remaining 90 split as usual
+    assert_eq!(
+        env.chain.query_balance(&creator, "ustars")?,
+        Uint128::new(10)
+    );
+    assert_eq!(
+        env.chain.query_balance(&env.payment_recipient, "ustars")?,
+        before + Uint128::new(81)
+    );
+    // stats count only the fee retained once the royalty is paid
+    assert_eq!(
+        app.infusion_stats(infusion_id)?.fees,
+        vec![coin(100 + 90, "ustars")]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_weighted_fee_recipients() -> anyhow::Result<()> {
+    let mut env = InfuserSuite::<MockBech32>::setup_fee_suite(BundleType::AllOf {}, vec![], false)?;
+    let app = env.infuser.clone();
+    let artist = env.chain.addr_make("artist");
+    let treasury = env.chain.addr_make("community-treasury");
+    let split = |weights: [&str; 3]| -> anyhow::Result<Vec<FeeRecipient>> {
+        Ok([&env.payment_recipient, &artist, &treasury]
+            .into_iter()
+            .zip(weights)
+            .map(|(addr, weight)| FeeRecipient {
+                addr: addr.clone(),
+                weight: Decimal::from_str(weight).unwrap(),
+            })
+            .collect())
+    };
+    env.infusion.infusion_params.mint_fee = Some(coin(101, "ustars").into());
+
+    // weights must sum to 100%
+    env.infusion.fee_recipients = Some(split(["0.5", "0.3", "0.1"])?);
+    assert_eq!(
+        app.execute(
+            &ExecuteMsg::CreateInfusion {
+                infusions: vec![env.infusion.clone()],
+            },
+            Some(&[coin(500, "ustars")]),
+        )
+        .unwrap_err()
+        .downcast::<ContractError>()?
+        .to_string(),
+        ContractError::FeeRecipientWeightsError {
+            total: Decimal::from_str("0.9")?
+        }
+        .to_string()
+    );
+
+    env.infusion.fee_recipients = Some(split(["0.5", "0.3", "0.2"])?);
+    let infusion_id = env.create_infusion()?;
 
-    let config = app.config()?;
+    let before = [&env.admin, &env.payment_recipient, &artist, &treasury]
+        .map(|addr| env.chain.query_balance(addr, "ustars").unwrap());
+    let res = app.call_as(&env.admin).execute(
+        &env.infuse_msg(infusion_id, 11),
+        Some(&[coin(101, "ustars")]),
+    )?;
+    // one transfer per recipient
     assert_eq!(
-        config,
-        Config {
-            latest_infusion_id: 4,
-            contract_owner: env.admin,
-            max_infusions: 2u64,
-            min_per_bundle: 1u64,
-            max_per_bundle: 10u64,
-            max_bundles: 5u64,
-            code_id: 2,
-            code_hash: HexBinary::from_hex(
-                "7e961e9369f7a3619b102834beec5bc2463f9008b40de972c91c45e3b300a805"
-            )?,
-            owner_fee: Decimal::zero(),
-            min_creation_fee: None,
-            min_infusion_fee: None,
-        }
+        res.event_attr_values("wasm-infusion-fee-split", "payment")
+            .len(),
+        4
     );
+
+    // 10 to the contract owner, the remaining 91 split 50/30/20 with the remainder to the last recipient
+    let after = [&env.admin, &env.payment_recipient, &artist, &treasury]
+        .map(|addr| env.chain.query_balance(addr, "ustars").unwrap());
+    assert_eq!(after[0], before[0] - Uint128::new(101 - 10));
+    assert_eq!(after[1], before[1] + Uint128::new(45));
+    assert_eq!(after[2], before[2] + Uint128::new(27));
+    assert_eq!(after[3], before[3] + Uint128::new(19));
+    assert!(env
+        .chain
+        .query_balance(&app.address()?, "ustars")?
+        .is_zero());
     Ok(())
 }
 
 #[test]
-fn test_successful_infusion() -> anyhow::Result<()> {
-    let env = InfuserSuite::<MockBech32>::setup()?;
+fn test_creation_fee_per_infusion() -> anyhow::Result<()> {
+    let env = InfuserSuite::<MockBech32>::setup_fee_suite(BundleType::AllOf {}, vec![], false)?;
     let app = env.infuser;
+    let mut second = env.infusion.clone();
+    second.infused_collection.name = "second".to_string();
+    second.infused_collection.symbol = "SECOND".to_string();
+    let both = vec![env.infusion.clone(), second];
 
-    // infuse
-    app.infuse(
-        vec![Bundle {
-            nfts: vec![
-                NFT {
-                    addr: env.nfts[0].clone(),
-                    token_id: 1,
-                },
-                NFT {
-                    addr: env.nfts[0].clone(),
-                    token_id: 3,
-                },
-            ],
-        }],
-        1,
-    )?;
-    // println!("{:#?}", res);
+    // creation fee is charged for every infusion created
+    assert_eq!(
+        app.execute(
+            &ExecuteMsg::CreateInfusion {
+                infusions: both.clone(),
+            },
+            Some(&[coin(500, "ustars")]),
+        )
+        .unwrap_err()
+        .downcast::<ContractError>()
+        .unwrap()
+        .to_string(),
+        ContractError::RequirednfusionFeeError {
+            fee: coin(1000, "ustars")
+        }
+        .to_string()
+    );
 
-    // confirm infused collection mint
-    let res = app.infusion_by_id(1)?;
-    assert!(
-        res.infused_collection.addr.is_some(),
-        "infusion collection not set!"
+    // both infusions are created in a single message, each with its own infused collection
+    let admin_before = env.chain.query_balance(&env.admin, "ustars")?;
+    app.execute(
+        &ExecuteMsg::CreateInfusion {
+            infusions: both.clone(),
+        },
+        Some(&[coin(1000, "ustars")]),
+    )?;
+    assert_eq!(
+        env.chain.query_balance(&env.admin, "ustars")?,
+        admin_before + Uint128::new(1000)
     );
     env.chain.wait_blocks(1)?;
 
-    // error if too few nfts provided in bundle
-    let err = app.infuse(vec![], 1).unwrap_err();
-    assert_eq!(err.source().unwrap().to_string(), "Bundle cannot be empty.");
-
-    // error if too many nfts provided in bundle
-    let err = app
-        .infuse(
-            vec![Bundle {
-                nfts: vec![
-                    NFT {
-                        addr: env.nfts[0].clone(),
-                        token_id: 2,
-                    },
-                    NFT {
-                        addr: env.nfts[0].clone(),
-                        token_id: 4,
-                    },
-                    NFT {
-                        addr: env.nfts[0].clone(),
-                        token_id: 6,
-                    },
-                ],
-            }],
-            1,
+    // other denoms are not accepted until set by the contract owner
+    assert!(app
+        .execute(
+            &ExecuteMsg::CreateInfusion {
+                infusions: vec![env.infusion.clone()],
+            },
+            Some(&[coin(50, "ubtsg")]),
         )
-        .unwrap_err();
-    println!("{:#?}", err);
+        .is_err());
+    app.call_as(&env.admin).execute(
+        &ExecuteMsg::UpdateConfig {
+            config: UpdatingConfig {
+                accepted_creation_fees: Some(vec![coin(50, "ubtsg").into()]),
+                ..Default::default()
+            },
+        },
+        None,
+    )?;
+    let config: Config = env.chain.query(&QueryMsg::Config {}, &app.address()?)?;
     assert_eq!(
-        err.source().unwrap().to_string(),
-        ContractError::BundleNotAccepted {
-            have: 3,
-            want: 2,
-            addr: env.nfts[0].to_string(),
-        }
-        .to_string()
+        config.accepted_creation_fees,
+        vec![coin(50, "ubtsg").into()]
     );
 
-    // assert queries
-    let res = app.infusion_by_id(1)?;
-    assert_eq!(res.collections.len(), 1);
-    assert_eq!(res.collections[0].min_req, 2);
-
-    // todo: finish asserting all queries work as expected
+    let btsg_before = env.chain.query_balance(&env.admin, "ubtsg")?;
+    app.execute(
+        &ExecuteMsg::CreateInfusion {
+            infusions: vec![env.infusion.clone()],
+        },
+        Some(&[coin(60, "ubtsg")]),
+    )?;
+    assert_eq!(
+        env.chain.query_balance(&env.admin, "ubtsg")?,
+        btsg_before + Uint128::new(50)
+    );
     Ok(())
 }
 
@@ -478,12 +2816,14 @@ fn test_allof_infuse_multiple_collections_in_bundle() -> anyhow::Result<()> {
             min_req: 2,
             max_req: None,
             payment_substitute: None,
+            source_royalty: None,
         },
         EligibleNFTCollection {
             addr: env.nfts[0].clone(),
             min_req: 4,
             max_req: None,
             payment_substitute: None,
+            source_royalty: None,
         },
     ];
 
@@ -493,12 +2833,14 @@ fn test_allof_infuse_multiple_collections_in_bundle() -> anyhow::Result<()> {
             min_req: 2,
             max_req: None,
             payment_substitute: None,
+            source_royalty: None,
         },
         EligibleNFTCollection {
             addr: env.nfts[1].clone(),
             min_req: 4,
             max_req: None,
             payment_substitute: None,
+            source_royalty: None,
         },
     ];
 
@@ -508,6 +2850,12 @@ fn test_allof_infuse_multiple_collections_in_bundle() -> anyhow::Result<()> {
         params: None,
         bundle_type: BundleType::AllOf {},
         wavs_enabled: false,
+        disposition: IngredientDisposition::Burn,
+        redemption: None,
+        fungible_ingredients: vec![],
+        pricing: None,
+        fee_discounts: vec![],
+        referral_share: None,
     };
 
     let mut infusion = Infusion {
@@ -515,6 +2863,7 @@ fn test_allof_infuse_multiple_collections_in_bundle() -> anyhow::Result<()> {
         infused_collection: good_infused.clone(),
         infusion_params: good_infusion_params,
         payment_recipient: Some(env.chain.sender.clone()),
+        fee_recipients: None,
         owner: None,
         description: Some("testewates".to_string()),
     };
@@ -570,6 +2919,8 @@ fn test_allof_infuse_multiple_collections_in_bundle() -> anyhow::Result<()> {
                 ],
             }],
             infusion_id,
+            None,
+            None,
         )
         .unwrap_err()
         .downcast::<ContractError>()?
@@ -611,6 +2962,8 @@ fn test_allof_infuse_multiple_collections_in_bundle() -> anyhow::Result<()> {
                 ],
             }],
             infusion_id,
+            None,
+            None,
         )
         .unwrap_err()
         .downcast::<ContractError>()?
@@ -657,6 +3010,8 @@ fn test_allof_infuse_multiple_collections_in_bundle() -> anyhow::Result<()> {
                 ],
             }],
             infusion_id,
+            None,
+            None,
         )
         .unwrap_err()
         .downcast::<ContractError>()?
@@ -732,6 +3087,8 @@ fn test_allof_infuse_multiple_collections_in_bundle() -> anyhow::Result<()> {
             },
         ],
         infusion_id,
+        None,
+        None,
     )?;
 
     // good infusion
@@ -754,12 +3111,14 @@ fn test_allof_eligible_nft_collections() -> anyhow::Result<()> {
             min_req: 2,
             max_req: None,
             payment_substitute: None,
+            source_royalty: None,
         },
         EligibleNFTCollection {
             addr: not_nft.clone(),
             min_req: 4,
             max_req: None,
             payment_substitute: None,
+            source_royalty: None,
         },
     ];
     let good_infused = InfuserSuite::<MockBech32>::default_infused_collection()?;
@@ -768,6 +3127,12 @@ fn test_allof_eligible_nft_collections() -> anyhow::Result<()> {
         params: None,
         bundle_type: BundleType::AllOf {},
         wavs_enabled: true,
+        disposition: IngredientDisposition::Burn,
+        redemption: None,
+        fungible_ingredients: vec![],
+        pricing: None,
+        fee_discounts: vec![],
+        referral_share: None,
     };
 
     let infusion = Infusion {
@@ -775,6 +3140,7 @@ fn test_allof_eligible_nft_collections() -> anyhow::Result<()> {
         infused_collection: good_infused.clone(),
         infusion_params: good_infusion_params,
         payment_recipient: Some(env.chain.sender),
+        fee_recipients: None,
         owner: None,
         description: Some("testewates".to_string()),
     };
@@ -819,23 +3185,19 @@ fn test_correct_fees() -> anyhow::Result<()> {
         .to_string()
     );
 
-    // err on more than required mint fee set
+    // overpaying the creation fee refunds the difference
+    let sender_before = env.chain.query_balance(&env.chain.sender, "ustars")?;
+    app.execute(
+        &ExecuteMsg::CreateInfusion {
+            infusions: vec![env.infusion.clone()],
+        },
+        Some(&[coin(501, "ustars")]),
+    )?;
     assert_eq!(
-        app.execute(
-            &ExecuteMsg::CreateInfusion {
-                infusions: vec![env.infusion.clone()],
-            },
-            Some(&[coin(501, "ustars")]),
-        )
-        .unwrap_err()
-        .downcast::<ContractError>()
-        .unwrap()
-        .to_string(),
-        ContractError::RequirednfusionFeeError {
-            fee: coin(500, "ustars")
-        }
-        .to_string()
+        env.chain.query_balance(&env.chain.sender, "ustars")?,
+        sender_before - Uint128::new(500)
     );
+    env.chain.wait_blocks(1)?;
 
     // good infusion creation
 
@@ -872,6 +3234,8 @@ fn test_correct_fees() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![bundle.clone()],
+                    recipient: None,
+                    referrer: None,
                 },
                 Some(&[coin(1, "ustars")]),
             )
@@ -893,6 +3257,8 @@ fn test_correct_fees() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![bundle.clone()],
+                    recipient: None,
+                    referrer: None,
                 },
                 &[coin(1, "ustars")],
                 &app.address()?,
@@ -914,6 +3280,8 @@ fn test_correct_fees() -> anyhow::Result<()> {
         &ExecuteMsg::Infuse {
             id: infusion_id,
             bundle: vec![bundle.clone()],
+            recipient: None,
+            referrer: None,
         },
         Some(&[coin(100, "ustars")]),
     );
@@ -930,6 +3298,8 @@ fn test_correct_fees() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![bundle.clone()],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(100, "ustars")]),
         )?
@@ -986,6 +3356,65 @@ fn test_correct_fees() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_infuse_many() -> anyhow::Result<()> {
+    let env = InfuserSuite::<MockBech32>::setup_fee_suite(BundleType::AllOf {}, vec![], false)?;
+    let app = env.infuser.clone();
+
+    let mut ids = vec![];
+    for _ in 0..2 {
+        let infusion_id = env.create_infusion()?;
+        ids.push(infusion_id);
+        env.chain.wait_blocks(1)?;
+    }
+
+    // funds remaining after the first item cannot cover the second items mint fee
+    assert_eq!(
+        app.call_as(&env.admin)
+            .execute(
+                &ExecuteMsg::InfuseMany {
+                    items: vec![
+                        (ids[0], vec![env.bundle(12)]),
+                        (ids[1], vec![env.bundle(13)])
+                    ],
+                },
+                Some(&[coin(150, "ustars")]),
+            )
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string(),
+        ContractError::InfuseManyItemFailed {
+            index: 1,
+            id: ids[1],
+            err: ContractError::FeeNotAccepted {
+                have: coin(50, "ustars"),
+                want: coin(100, "ustars"),
+            }
+            .to_string(),
+        }
+        .to_string()
+    );
+
+    // each item pays its own mint fee
+    app.call_as(&env.admin).execute(
+        &ExecuteMsg::InfuseMany {
+            items: vec![
+                (ids[0], vec![env.bundle(12)]),
+                (ids[1], vec![env.bundle(13)]),
+            ],
+        },
+        Some(&[coin(200, "ustars")]),
+    )?;
+    assert_eq!(
+        env.chain
+            .query_balance(&env.payment_recipient, "ustars")?
+            .u128(),
+        180u128
+    );
+
+    Ok(())
+}
+
 // Correct Trait Requirement Logic
 #[test]
 fn test_anyof_infuse_multiple_collections_in_bundle() -> anyhow::Result<()> {
@@ -1010,7 +3439,7 @@ fn test_allof_infusion_fee() -> anyhow::Result<()> {
 
     env.infusion.collections[0].min_req = 2;
     // update substitute payment for infusion being created to 200ustars
-    env.infusion.collections[1].payment_substitute = Some(coin(200u128, "ustars"));
+    env.infusion.collections[1].payment_substitute = Some(coin(200u128, "ustars").into());
 
     // good infusion creation
     let infusion_id = app
@@ -1046,6 +3475,8 @@ fn test_allof_infusion_fee() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![bundle.clone()],
+                    recipient: None,
+                    referrer: None,
                 },
                 Some(&[coin(100, "ustars")]),
             )
@@ -1072,6 +3503,8 @@ fn test_allof_infusion_fee() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![bundle.clone()],
+                    recipient: None,
+                    referrer: None,
                 },
                 Some(&[coin(500, "ustars")]),
             )
@@ -1091,6 +3524,8 @@ fn test_allof_infusion_fee() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![bundle.clone()],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(300, "ustars")]),
         )?
@@ -1119,6 +3554,8 @@ fn test_allof_infusion_fee() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![bundle.clone()],
+                    recipient: None,
+                    referrer: None,
                 },
                 Some(&[coin(50, "ustars")]),
             )
@@ -1138,6 +3575,8 @@ fn test_allof_infusion_fee() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![bundle.clone()],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(300, "ustars")]),
         )?
@@ -1171,6 +3610,8 @@ fn test_allof_infusion_fee() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![bundle.clone()],
+                    recipient: None,
+                    referrer: None,
                 },
                 Some(&[coin(300, "ustars")]),
             )
@@ -1203,6 +3644,8 @@ fn test_allof_infusion_fee() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![bundle.clone()],
+                    recipient: None,
+                    referrer: None,
                 },
                 Some(&[coin(300, "ustars")]),
             )
@@ -1235,6 +3678,8 @@ fn test_allof_infusion_fee() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![bundle.clone()],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(300, "ustars")]),
         )?
@@ -1309,7 +3754,7 @@ fn test_anyof_infusion_fee() -> anyhow::Result<()> {
     env.infusion.infusion_params.bundle_type = bundle_type;
 
     // set fee substitute for only one of them
-    env.infusion.collections[1].payment_substitute = Some(coin(200u128, "ustars"));
+    env.infusion.collections[1].payment_substitute = Some(coin(200u128, "ustars").into());
 
     // good infusion creation
     let infusion_id = Uint128::from_str(
@@ -1341,6 +3786,8 @@ fn test_anyof_infusion_fee() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![bundle.clone()],
+                    recipient: None,
+                    referrer: None,
                 },
                 Some(&[coin(100, "ustars")]),
             )
@@ -1361,6 +3808,8 @@ fn test_anyof_infusion_fee() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![bundle.clone()],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(150, "ustars")]),
         )?
@@ -1389,6 +3838,8 @@ fn test_anyof_infusion_fee() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![bundle.clone()],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(300, "ustars")]),
         )?
@@ -1415,6 +3866,8 @@ fn test_anyof_infusion_fee() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![bundle.clone()],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(300, "ustars")]),
         )?
@@ -1432,6 +3885,8 @@ fn test_anyof_infusion_fee() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![bundle.clone()],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(300, "ustars")]),
         )?
@@ -1448,6 +3903,8 @@ fn test_anyof_infusion_fee() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![bundle.clone()],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(500, "ustars")]),
         )?
@@ -1464,6 +3921,8 @@ fn test_anyof_infusion_fee() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![bundle.clone()],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(700, "ustars")]),
         )?
@@ -1508,13 +3967,16 @@ fn test_anyof_mix() -> anyhow::Result<()> {
     let mut bundle = Bundle { nfts: vec![] };
 
     // set parameters as infusion #1 for
-    env.infusion.collections[0].payment_substitute = Some(coin(100u128, "ustars"));
+    env.infusion.collections[0].payment_substitute = Some(coin(100u128, "ustars").into());
     env.infusion.collections[0].min_req = 1;
-    env.infusion.collections[1].payment_substitute = Some(coin(
-        100u128,
-        "ibc/4A1C18CA7F50544760CF306189B810CE4C1CB156C7FC870143D401FE7280E591",
-    ));
-    env.infusion.infusion_params.mint_fee = Some(coin(2000, "ustars"));
+    env.infusion.collections[1].payment_substitute = Some(
+        coin(
+            100u128,
+            "ibc/4A1C18CA7F50544760CF306189B810CE4C1CB156C7FC870143D401FE7280E591",
+        )
+        .into(),
+    );
+    env.infusion.infusion_params.mint_fee = Some(coin(2000, "ustars").into());
     env.infusion.collections[1].min_req = 1;
 
     env.infusion.infusion_params.bundle_type = BundleType::AnyOf {
@@ -1525,12 +3987,14 @@ fn test_anyof_mix() -> anyhow::Result<()> {
         min_req: 2,
         max_req: None,
         payment_substitute: None,
+        source_royalty: None,
     });
     env.infusion.collections.push(EligibleNFTCollection {
         addr: nft4.clone(),
         min_req: 4,
         max_req: None,
         payment_substitute: None,
+        source_royalty: None,
     });
 
     // create infusion
@@ -1557,6 +4021,8 @@ fn test_anyof_mix() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![bundle.clone()],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(2000, "ustars")]),
         )?
@@ -1576,6 +4042,8 @@ fn test_anyof_mix() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![bundle.clone()],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(2100, "ustars")]),
         )?
@@ -1595,6 +4063,8 @@ fn test_anyof_mix() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![bundle.clone()],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(2200, "ustars")]),
         )?
@@ -1611,6 +4081,8 @@ fn test_anyof_mix() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![bundle.clone()],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(2199, "ustars")]),
         )?
@@ -1636,6 +4108,8 @@ fn test_anyof_mix() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![bundle.clone()],
+                recipient: None,
+                referrer: None,
             },
             Some(&[
                 coin(2000, "ustars"),
@@ -1689,10 +4163,13 @@ fn test_allof_payment_substitute() -> anyhow::Result<()> {
     )?;
 
     // update substitute payment for infusion being created to 200ustars
-    env.infusion.collections[1].payment_substitute = Some(coin(
-        200u128,
-        "ibc/4A1C18CA7F50544760CF306189B810CE4C1CB156C7FC870143D401FE7280E591",
-    ));
+    env.infusion.collections[1].payment_substitute = Some(
+        coin(
+            200u128,
+            "ibc/4A1C18CA7F50544760CF306189B810CE4C1CB156C7FC870143D401FE7280E591",
+        )
+        .into(),
+    );
 
     // good infusion creation
     let infusion_id = Uint128::from_str(
@@ -1715,6 +4192,8 @@ fn test_allof_payment_substitute() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![],
+                    recipient: None,
+                    referrer: None,
                 },
                 Some(&[]),
             )
@@ -1731,6 +4210,8 @@ fn test_allof_payment_substitute() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![bundle.clone()],
+                    recipient: None,
+                    referrer: None,
                 },
                 Some(&[]),
             )
@@ -1758,6 +4239,8 @@ fn test_allof_payment_substitute() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![bundle.clone()],
+                    recipient: None,
+                    referrer: None,
                 },
                 Some(&[
                     coin(100, "ustars"),
@@ -1792,6 +4275,8 @@ fn test_allof_payment_substitute() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![bundle.clone()],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(100, "ustars")]),
         )
@@ -1825,6 +4310,8 @@ fn test_allof_payment_substitute() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![bundle.clone()],
+                    recipient: None,
+                    referrer: None,
                 },
                 Some(&[coin(200, "ustars")]),
             )
@@ -1847,6 +4334,8 @@ fn test_allof_payment_substitute() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![bundle.clone()],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(300, "ustars")]),
         )
@@ -1876,6 +4365,8 @@ fn test_allof_payment_substitute() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![bundle.clone()],
+                    recipient: None,
+                    referrer: None,
                 },
                 Some(&[coin(100, "ustars"), coin(200, "ubtsg")]),
             )
@@ -1901,6 +4392,8 @@ fn test_allof_payment_substitute() -> anyhow::Result<()> {
         &ExecuteMsg::Infuse {
             id: infusion_id,
             bundle: vec![bundle.clone()],
+            recipient: None,
+            referrer: None,
         },
         Some(&[
             coin(100, "ustars"),
@@ -1915,14 +4408,15 @@ fn test_allof_payment_substitute() -> anyhow::Result<()> {
 
     // check with multiple collections
     env.infusion.infused_collection.addr = None;
-    env.infusion.collections[0].payment_substitute = Some(coin(200u128, "ustars"));
+    env.infusion.collections[0].payment_substitute = Some(coin(200u128, "ustars").into());
     env.infusion.collections[0].max_req = Some(env.infusion.collections[0].min_req);
 
     env.infusion.collections.push(EligibleNFTCollection {
         addr: nft3.clone(),
         min_req: 1u64,
         max_req: Some(1u64),
-        payment_substitute: Some(coin(200u128, "ubtsg")),
+        payment_substitute: Some(coin(200u128, "ubtsg").into()),
+        source_royalty: None,
     });
     // env.infusion.collections[1].max_req = Some(env.infusion.collections[0].min_req);
     // env.infusion.collections[2].max_req = Some(env.infusion.collections[0].min_req);
@@ -1949,6 +4443,8 @@ fn test_allof_payment_substitute() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![Bundle { nfts: vec![] }],
+                    recipient: None,
+                    referrer: None,
                 },
                 None
             )
@@ -1969,6 +4465,8 @@ fn test_allof_payment_substitute() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![Bundle { nfts: vec![] }],
+                    recipient: None,
+                    referrer: None,
                 },
                 Some(&[coin(100, "ustars")]),
             )
@@ -1989,6 +4487,8 @@ fn test_allof_payment_substitute() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![Bundle { nfts: vec![] }],
+                    recipient: None,
+                    referrer: None,
                 },
                 Some(&[coin(300, "ustars")]),
             )
@@ -2017,6 +4517,8 @@ fn test_allof_payment_substitute() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![Bundle { nfts: vec![] }],
+                    recipient: None,
+                    referrer: None,
                 },
                 Some(&[
                     coin(500, "ustars"),
@@ -2079,7 +4581,7 @@ fn test_anyof_payment_substitute() -> anyhow::Result<()> {
 
     let nft1 = env.nfts[0].clone();
     let nft2 = env.nfts[1].clone();
-    env.infusion.collections[1].payment_substitute = Some(nft2_feesub.clone());
+    env.infusion.collections[1].payment_substitute = Some(nft2_feesub.clone().into());
     env.infusion.infusion_params.bundle_type = BundleType::AnyOf {
         addrs: vec![nft1.clone(), nft2.clone()],
     };
@@ -2105,6 +4607,8 @@ fn test_anyof_payment_substitute() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![],
+                    recipient: None,
+                    referrer: None,
                 },
                 None
             )
@@ -2121,6 +4625,8 @@ fn test_anyof_payment_substitute() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![bundle.clone()],
+                    recipient: None,
+                    referrer: None,
                 },
                 None
             )
@@ -2142,6 +4648,8 @@ fn test_anyof_payment_substitute() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![bundle.clone()],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(100, "ustars")]),
         )?
@@ -2160,6 +4668,8 @@ fn test_anyof_payment_substitute() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![bundle.clone()],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(200, "ustars")]),
         )?
@@ -2177,6 +4687,8 @@ fn test_anyof_payment_substitute() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![bundle.clone()],
+                recipient: None,
+                referrer: None,
             },
             Some(&[
                 coin(100, "ustars"),
@@ -2196,12 +4708,13 @@ fn test_anyof_payment_substitute() -> anyhow::Result<()> {
     // set owner
     // check with multiple collections
     env.infusion.infused_collection.addr = None;
-    env.infusion.collections[0].payment_substitute = Some(coin(200u128, "ustars"));
+    env.infusion.collections[0].payment_substitute = Some(coin(200u128, "ustars").into());
     env.infusion.collections.push(EligibleNFTCollection {
         addr: env.nfts[2].clone(),
         min_req: 1u64,
         max_req: Some(1u64),
-        payment_substitute: Some(coin(200u128, "ubtsg")),
+        payment_substitute: Some(coin(200u128, "ubtsg").into()),
+        source_royalty: None,
     });
     env.chain.wait_blocks(2)?;
 
@@ -2224,6 +4737,8 @@ fn test_anyof_payment_substitute() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![Bundle { nfts: vec![] }],
+                    recipient: None,
+                    referrer: None,
                 },
                 Some(&[]),
             )
@@ -2240,6 +4755,8 @@ fn test_anyof_payment_substitute() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![Bundle { nfts: vec![] }],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(300, "ustars")]),
         )?
@@ -2262,6 +4779,8 @@ fn test_anyof_payment_substitute() -> anyhow::Result<()> {
         &ExecuteMsg::Infuse {
             id: infusion_id,
             bundle: vec![Bundle { nfts: vec![] }],
+            recipient: None,
+            referrer: None,
         },
         Some(&[coin(300, "ustars")]),
     )?;
@@ -2296,8 +4815,8 @@ fn test_anyof_payment_substitute() -> anyhow::Result<()> {
         .payment_substitute
         .clone()
         .unwrap()
-        .amount
-        .checked_add(inf.infusion_params.mint_fee.unwrap().amount)?;
+        .amount()
+        .checked_add(inf.infusion_params.mint_fee.unwrap().amount())?;
     let admin_cut = pay.multiply_ratio(cfg.owner_fee.numerator(), cfg.owner_fee.denominator());
     println!("pay: {:#?}", pay);
     println!("admin_cut: {:#?}", admin_cut);
@@ -2378,7 +4897,7 @@ fn test_updating_infusion_eligible_collections() -> anyhow::Result<()> {
     let app = env.infuser;
 
     // update infusion collect params to undesired state
-    env.infusion.collections[1].payment_substitute = Some(coin(1u128, "ustars"));
+    env.infusion.collections[1].payment_substitute = Some(coin(1u128, "ustars").into());
 
     // good infusion creation
     let infusion_id = app
@@ -2393,7 +4912,7 @@ fn test_updating_infusion_eligible_collections() -> anyhow::Result<()> {
     // update infusion accepted params
     env.infusion.collections[1].max_req = Some(3);
     env.infusion.collections[1].min_req = 2;
-    env.infusion.collections[1].payment_substitute = Some(coin(100, "ubtsg"));
+    env.infusion.collections[1].payment_substitute = Some(coin(100, "ubtsg").into());
     app.update_infusions_eligible_collections_or_bundle_type(
         env.infusion.infusion_params.bundle_type,
         infusion_id,
@@ -2407,9 +4926,161 @@ fn test_updating_infusion_eligible_collections() -> anyhow::Result<()> {
     assert_eq!(infusion.collections[0].min_req, 2);
     assert_eq!(
         infusion.collections[0].payment_substitute,
-        Some(coin(100, "ubtsg"))
+        Some(coin(100, "ubtsg").into())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_wavs_signed_submissions() -> anyhow::Result<()> {
+    let env = InfuserSuite::<MockBech32>::setup_fee_suite(BundleType::AllOf {}, vec![], true)?;
+    let app = env.infuser;
+    let relayer = env.chain.addr_make("relayer");
+    let operator = SigningKey::from_bytes(&[7u8; 32]);
+    let public_key = Binary::from(operator.verifying_key().to_bytes().to_vec());
+    let chain_id = env.chain.block_info()?.chain_id;
+    let bundles = vec![WavsBundle {
+        infuser: env.admin.to_string(),
+        nft_addr: env.nfts[0].to_string(),
+        infused_ids: vec!["11".to_string()],
+    }];
+    let sign = |nonce: u64| -> anyhow::Result<Vec<WavsSignature>> {
+        let hash = wavs_signing_hash(&app.address()?, &chain_id, nonce, &bundles)?;
+        Ok(vec![WavsSignature {
+            public_key: public_key.clone(),
+            signature: Binary::from(operator.sign(&hash).to_bytes().to_vec()),
+        }])
+    };
+    let submit = |nonce: u64, signatures: Vec<WavsSignature>| -> anyhow::Result<String> {
+        Ok(app
+            .call_as(&relayer)
+            .execute(
+                &ExecuteMsg::WavsSignedEntryPoint {
+                    infusions: bundles.clone(),
+                    nonce,
+                    signatures,
+                },
+                None,
+            )
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string())
+    };
+
+    // signatures from unregistered keys are rejected
+    assert_eq!(
+        submit(1, sign(1)?)?,
+        ContractError::UnknownWavsOperator {
+            public_key: public_key.to_base64()
+        }
+        .to_string()
+    );
+
+    // only the contract owner registers operators
+    let register = ExecuteMsg::UpdateWavsOperators {
+        to_add: vec![WavsOperator {
+            key_type: WavsKeyType::Ed25519,
+            public_key: public_key.clone(),
+        }],
+        to_remove: vec![],
+        threshold: None,
+    };
+    assert!(app.call_as(&relayer).execute(&register, None).is_err());
+    app.call_as(&env.admin).execute(&register, None)?;
+
+    // signatures over a different nonce are rejected
+    assert_eq!(
+        submit(1, sign(2)?)?,
+        ContractError::InvalidWavsSignature {
+            public_key: public_key.to_base64()
+        }
+        .to_string()
+    );
+
+    // signed submissions can be relayed by anyone
+    app.call_as(&relayer).execute(
+        &ExecuteMsg::WavsSignedEntryPoint {
+            infusions: bundles.clone(),
+            nonce: 1,
+            signatures: sign(1)?,
+        },
+        None,
+    )?;
+    let res = app.wavs_record(vec![env.nfts[0].to_string()], Some(env.admin.clone()))?;
+    assert_eq!(res[0].count, Some(1u64));
+
+    // nonces must increase for each operator, so cannot be replayed
+    assert_eq!(app.wavs_operator_nonce(public_key.clone())?, 1);
+    assert_eq!(
+        submit(1, sign(1)?)?,
+        ContractError::StaleWavsNonce { nonce: 1, last: 1 }.to_string()
+    );
+    assert_eq!(
+        submit(0, sign(0)?)?,
+        ContractError::StaleWavsNonce { nonce: 0, last: 1 }.to_string()
+    );
+
+    // the operator registered at instantiation tracks its own nonce
+    let mut other = bundles.clone();
+    other[0].infused_ids = vec!["12".to_string()];
+    wavs_submit(&app, &env.wavs_operator, other)?;
+    let operator_key = wavs_operator(&env.wavs_operator).public_key;
+    assert_eq!(app.wavs_operator_nonce(operator_key)?, 1);
+    let res = app.wavs_record(vec![env.nfts[0].to_string()], Some(env.admin.clone()))?;
+    assert_eq!(res[0].count, Some(2u64));
+    Ok(())
+}
+
+#[test]
+fn test_wavs_burn_dedup() -> anyhow::Result<()> {
+    let env = InfuserSuite::<MockBech32>::setup_fee_suite(BundleType::AllOf {}, vec![], true)?;
+    let app = env.infuser;
+    let credited = |token_id: &str| -> anyhow::Result<bool> {
+        Ok(env.chain.query(
+            &QueryMsg::WavsBurnCredited {
+                collection: env.nfts[0].to_string(),
+                token_id: token_id.to_string(),
+            },
+            &app.address()?,
+        )?)
+    };
+    let wavs_bundle = |ids: &[&str]| {
+        vec![WavsBundle {
+            infuser: env.admin.to_string(),
+            nft_addr: env.nfts[0].to_string(),
+            infused_ids: ids.iter().map(|id| id.to_string()).collect(),
+        }]
+    };
+
+    assert!(!credited("11")?);
+    wavs_submit(&app, &env.wavs_operator, wavs_bundle(&["11"]))?;
+    assert!(credited("11")?);
+
+    // a burn reported again is rejected, including alongside new burns
+    assert_eq!(
+        wavs_submit(&app, &env.wavs_operator, wavs_bundle(&["12", "11"]))
+            .unwrap_err()
+            .downcast::<ContractError>()?
+            .to_string(),
+        ContractError::WavsBurnAlreadyCredited {
+            collection: env.nfts[0].to_string(),
+            token_id: "11".to_string(),
+        }
+        .to_string()
     );
+    assert!(!credited("12")?);
+
+    // as are duplicates within a single report
+    assert!(wavs_submit(&app, &env.wavs_operator, wavs_bundle(&["13", "13"])).is_err());
 
+    // collections are validated before burns are credited
+    let mut invalid = wavs_bundle(&["14"]);
+    invalid[0].nft_addr = "not-a-collection".to_string();
+    assert!(wavs_submit(&app, &env.wavs_operator, invalid).is_err());
+
+    let res = app.wavs_record(vec![env.nfts[0].to_string()], Some(env.admin.clone()))?;
+    assert_eq!(res[0].count, Some(1u64));
     Ok(())
 }
 
@@ -2420,7 +5091,7 @@ fn test_wavs_record_anyof() -> anyhow::Result<()> {
     // let mut bundles = vec![];
     let mut env = InfuserSuite::<MockBech32>::setup_fee_suite(BundleType::AllOf {}, vec![], true)?;
     let app = env.infuser;
-    let not_wavs = env.chain.addr_make("not-wavs");
+    let not_wavs = SigningKey::from_bytes(&[8u8; 32]);
 
     let nft1 = env.nfts[0].clone();
     let nft2 = env.nfts[1].clone();
@@ -2432,7 +5103,7 @@ fn test_wavs_record_anyof() -> anyhow::Result<()> {
     env.infusion.infusion_params.wavs_enabled = true;
     env.infusion.collections[0].min_req = 2;
     env.infusion.collections[1].min_req = 1;
-    env.infusion.collections[1].payment_substitute = Some(coin(100u128, "ustars"));
+    env.infusion.collections[1].payment_substitute = Some(coin(100u128, "ustars").into());
     env.infusion.infusion_params.bundle_type = BundleType::AnyOf {
         addrs: vec![nft1.clone()],
     };
@@ -2453,13 +5124,14 @@ fn test_wavs_record_anyof() -> anyhow::Result<()> {
 
     println!("assert only wavs operator can update");
     assert_eq!(
-        app.call_as(&not_wavs)
-            .wavs_entry_point(wavs_bundle)
+        wavs_submit(&app, &not_wavs, wavs_bundle)
             .unwrap_err()
-            .source()
-            .unwrap()
+            .downcast::<ContractError>()?
             .to_string(),
-        "Caller is not admin"
+        ContractError::UnknownWavsOperator {
+            public_key: wavs_operator(&not_wavs).public_key.to_base64()
+        }
+        .to_string()
     );
 
     println!("assert nft is recorded to state");
@@ -2468,8 +5140,7 @@ fn test_wavs_record_anyof() -> anyhow::Result<()> {
         nft_addr: env.nfts[0].to_string(),
         infused_ids: vec!["11".to_string()],
     }];
-    app.call_as(&env.wavs_service.clone())
-        .wavs_entry_point(wavs_bundle.clone())?;
+    wavs_submit(&app, &env.wavs_operator, wavs_bundle.clone())?;
 
     let res = app
         .call_as(&env.admin)
@@ -2477,6 +5148,8 @@ fn test_wavs_record_anyof() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(200, "ustars")]),
         )?
@@ -2493,6 +5166,8 @@ fn test_wavs_record_anyof() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![],
+                    recipient: None,
+                    referrer: None,
                 },
                 Some(&[coin(100, "ustars")]),
             )
@@ -2524,8 +5199,7 @@ fn test_wavs_record_anyof() -> anyhow::Result<()> {
     //     }],
     // }];
 
-    // app.call_as(&env.wavs_service.clone())
-    //     .wavs_entry_point(wavs_bundle.clone())?;
+    // wavs_submit(&app, &env.wavs_operator, wavs_bundle.clone())?;
 
     // let res = app
     //     .call_as(&env.admin)
@@ -2558,7 +5232,7 @@ fn test_wavs_record_allof() -> anyhow::Result<()> {
     let mut bundles = vec![];
     let mut env = InfuserSuite::<MockBech32>::setup_fee_suite(BundleType::AllOf {}, vec![], true)?;
     let app = env.infuser;
-    let not_wavs = env.chain.addr_make("not-wavs");
+    let not_wavs = SigningKey::from_bytes(&[8u8; 32]);
 
     // with/without payment substitute
     // with 0 nfts sent in bundle
@@ -2566,7 +5240,7 @@ fn test_wavs_record_allof() -> anyhow::Result<()> {
     println!(" update infusion collect params to undesired state");
     env.infusion.infusion_params.wavs_enabled = true;
     env.infusion.collections[0].min_req = 2;
-    env.infusion.collections[1].payment_substitute = Some(coin(100u128, "ustars"));
+    env.infusion.collections[1].payment_substitute = Some(coin(100u128, "ustars").into());
 
     println!("good infusion creation");
     let infusion_id = Uint128::from_str(
@@ -2584,13 +5258,14 @@ fn test_wavs_record_allof() -> anyhow::Result<()> {
 
     println!("assert only wavs operator can update");
     assert_eq!(
-        app.call_as(&not_wavs)
-            .wavs_entry_point(wavs_bundle)
+        wavs_submit(&app, &not_wavs, wavs_bundle)
             .unwrap_err()
-            .source()
-            .unwrap()
+            .downcast::<ContractError>()?
             .to_string(),
-        "Caller is not admin"
+        ContractError::UnknownWavsOperator {
+            public_key: wavs_operator(&not_wavs).public_key.to_base64()
+        }
+        .to_string()
     );
 
     println!("assert nft is recorded to state");
@@ -2599,12 +5274,11 @@ fn test_wavs_record_allof() -> anyhow::Result<()> {
         nft_addr: env.nfts[0].to_string(),
         infused_ids: vec!["11".to_string()],
     }];
-    app.call_as(&env.wavs_service.clone())
-        .wavs_entry_point(wavs_bundle.clone())?;
+    wavs_submit(&app, &env.wavs_operator, wavs_bundle.clone())?;
 
     assert_eq!(
         app.call_as(&env.admin)
-            .infuse(vec![], infusion_id)
+            .infuse(vec![], infusion_id, None, None)
             .unwrap_err()
             .source()
             .unwrap()
@@ -2618,8 +5292,7 @@ fn test_wavs_record_allof() -> anyhow::Result<()> {
 
     println!("use record from WAVS expect to mint 1 NFT");
     wavs_bundle[0].infused_ids = vec![12.to_string()];
-    app.call_as(&env.wavs_service.clone())
-        .wavs_entry_point(wavs_bundle.clone())?;
+    wavs_submit(&app, &env.wavs_operator, wavs_bundle.clone())?;
 
     let res = app
         .call_as(&env.admin)
@@ -2627,6 +5300,8 @@ fn test_wavs_record_allof() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(200, "ustars")]),
         )?
@@ -2642,6 +5317,8 @@ fn test_wavs_record_allof() -> anyhow::Result<()> {
                 &ExecuteMsg::Infuse {
                     id: infusion_id,
                     bundle: vec![],
+                    recipient: None,
+                    referrer: None,
                 },
                 Some(&[coin(200, "ustars")]),
             )
@@ -2673,8 +5350,7 @@ fn test_wavs_record_allof() -> anyhow::Result<()> {
         }],
     }];
 
-    app.call_as(&env.wavs_service.clone())
-        .wavs_entry_point(wavs_bundle.clone())?;
+    wavs_submit(&app, &env.wavs_operator, wavs_bundle.clone())?;
 
     let res = app
         .call_as(&env.admin)
@@ -2682,6 +5358,8 @@ fn test_wavs_record_allof() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: bundles.clone(),
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(200, "ustars")]),
         )?
@@ -2696,9 +5374,8 @@ fn test_wavs_record_allof() -> anyhow::Result<()> {
         "ensure we accurately retain any excess mint count if min burnt is recorded in store
      (1st tx mints one), add one more"
     );
-    wavs_bundle[0].infused_ids.push("16".into());
-    app.call_as(&env.wavs_service.clone())
-        .wavs_entry_point(wavs_bundle.clone())?;
+    wavs_bundle[0].infused_ids = vec!["16".into(), "17".into()];
+    wavs_submit(&app, &env.wavs_operator, wavs_bundle.clone())?;
 
     bundles[0].nfts[0].token_id = 12u64;
 
@@ -2708,6 +5385,8 @@ fn test_wavs_record_allof() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: bundles.clone(),
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(200, "ustars")]),
         )?
@@ -2717,9 +5396,8 @@ fn test_wavs_record_allof() -> anyhow::Result<()> {
     assert_eq!(res[0], "burn");
     assert_eq!(res[1], "mint");
 
-    wavs_bundle[0].infused_ids = vec![16u64.to_string()];
-    app.call_as(&env.wavs_service.clone())
-        .wavs_entry_point(wavs_bundle.clone())?;
+    wavs_bundle[0].infused_ids = vec![18u64.to_string()];
+    wavs_submit(&app, &env.wavs_operator, wavs_bundle.clone())?;
 
     let res = app.wavs_record(vec![env.nfts[0].to_string()], Some(env.admin.clone()))?;
     println!("{:#?}", res);
@@ -2732,6 +5410,8 @@ fn test_wavs_record_allof() -> anyhow::Result<()> {
             &ExecuteMsg::Infuse {
                 id: infusion_id,
                 bundle: vec![],
+                recipient: None,
+                referrer: None,
             },
             Some(&[coin(200, "ustars")]),
         )?
@@ -0,0 +1,56 @@
+//! Minimal price oracle implementing the infuser's oracle query interface.
+use abstract_cw_multi_test::Contract;
+use cosmwasm_std::{
+    to_json_binary, Binary, Decimal, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
+    Timestamp,
+};
+use cw_infusions::pricing::{OraclePriceResponse, OracleQueryMsg};
+use cw_orch::prelude::*;
+use cw_storage_plus::Map;
+
+const PRICES: Map<&str, OraclePriceResponse> = Map::new("prices");
+
+#[cosmwasm_schema::cw_serde]
+pub enum MockOracleExecuteMsg {
+    /// Sets the price of a denom, updated at the current block time unless set.
+    SetPrice {
+        denom: String,
+        price: Decimal,
+        updated_at: Option<Timestamp>,
+    },
+}
+
+fn instantiate(_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+fn execute(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: MockOracleExecuteMsg,
+) -> StdResult<Response> {
+    let MockOracleExecuteMsg::SetPrice {
+        denom,
+        price,
+        updated_at,
+    } = msg;
+    PRICES.save(
+        deps.storage,
+        &denom,
+        &OraclePriceResponse {
+            price,
+            updated_at: updated_at.unwrap_or(env.block.time),
+        },
+    )?;
+    Ok(Response::new())
+}
+
+fn query(deps: Deps, _env: Env, msg: OracleQueryMsg) -> StdResult<Binary> {
+    let OracleQueryMsg::Price { denom } = msg;
+    to_json_binary(&PRICES.load(deps.storage, &denom)?)
+}
+
+pub fn mock_oracle_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(execute, instantiate, query))
+}